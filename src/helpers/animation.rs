@@ -0,0 +1,46 @@
+use std::{
+    io::{IsTerminal, Write},
+    time::Duration,
+};
+
+use super::{art::Art, interrupt, print::print_boykisserline};
+
+// Cycles through the frames of `art` until Ctrl-C. The cursor is expected
+// right below the `rows` rows already printed with the art's first frame,
+// only the art column of those rows is redrawn.
+pub fn play(art: &Art, palette: &[String], rows: u16) {
+    if !art.is_animated() || !std::io::stdout().is_terminal() {
+        return;
+    }
+
+    interrupt::catch();
+
+    let mut stdout = std::io::stdout();
+    let height = art.height() as u16;
+    let mut frame = 0;
+
+    print!("\u{1b}[?25l");
+    let _ = stdout.flush();
+
+    while interrupt::wait(Duration::from_millis(art.frames[frame].delay)) {
+        frame = (frame + 1) % art.frames.len();
+
+        print!("\u{1b}[{}A", rows);
+        for line in 0..height {
+            print!("\r");
+            print_boykisserline(line, frame, art, palette);
+            println!();
+        }
+
+        // "\e[0B" still moves one row in some terminals.
+        if rows > height {
+            print!("\u{1b}[{}B", rows - height);
+        }
+
+        print!("\r\u{1b}[0m");
+        let _ = stdout.flush();
+    }
+
+    print!("\r\u{1b}[0m\u{1b}[?25h");
+    let _ = stdout.flush();
+}
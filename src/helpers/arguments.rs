@@ -1,11 +1,39 @@
-use crate::helpers::colors::COLORS;
+use crate::helpers::{colors::COLORS, theme::{Theme, LABEL_CASES}};
+use crate::system::custom::CustomModule;
+
+pub const UPTIME_STYLES: [&str; 3] = ["long", "short", "seconds"];
+
+// Seconds between refreshes of --watch without a value.
+pub const WATCH_INTERVAL: f64 = 2.0;
 
 #[derive(Debug)]
 pub struct Arguments {
     pub help: bool,
     pub color: String,
+    pub color_given: bool,
     pub list: bool,
-    pub boykisser: String   
+    pub boykisser: String,
+    pub uptime: String,
+    pub date_format: String,
+    pub custom: Vec<CustomModule>,
+    pub command_timeout: u64,
+    pub cpu_temp: bool,
+    pub cpu_sparkline: bool,
+    pub init_services: bool,
+    pub modules: Vec<String>,
+    pub list_modules: bool,
+    pub record_commands: Option<String>,
+    pub image: Option<String>,
+    pub image_protocol: String,
+    pub animate: bool,
+    pub watch: Option<f64>,
+    pub mirror: bool,
+    pub flip: bool,
+    pub scale: f64,
+    pub max_height: Option<usize>,
+    pub check_art: bool,
+    pub theme: Theme,
+    pub list_themes: bool
 }
 
 impl Arguments {
@@ -33,6 +61,53 @@ impl Arguments {
         std::process::exit(1);
     }   
 
+    fn validate_uptime(style: String) -> String {
+        if UPTIME_STYLES.contains(&style.as_str()) {
+            return style;
+        }
+
+        Self::print_err("Invalid uptime style provided.");
+        std::process::exit(1);
+    }
+
+    fn validate_number(number: String, name: &str) -> String {
+        if number.parse::<usize>().is_ok() {
+            return number;
+        }
+
+        Self::print_err(&format!("Invalid {} provided.", name));
+        std::process::exit(1);
+    }
+
+    fn validate_label_case(case: String) -> String {
+        if LABEL_CASES.contains(&case.as_str()) {
+            return case;
+        }
+
+        Self::print_err("Invalid label case provided.");
+        std::process::exit(1);
+    }
+
+    fn validate_theme(name: &str) -> Theme {
+        match Theme::load(name) {
+            Ok(theme) => theme,
+            Err(err) => {
+                Self::print_err(&err);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    fn validate_custom(value: &str, file: bool) -> CustomModule {
+        match CustomModule::parse(value, file) {
+            Some(module) => module,
+            None => {
+                Self::print_err("Invalid custom module, expected <label>:<value>.");
+                std::process::exit(1);
+            }
+        }
+    }
+
     fn print_help() {
         println!("Usage: boykisserfetch [OPTION]...");
         println!("Prints a boykisser with system information.");
@@ -41,6 +116,33 @@ impl Arguments {
             -c=<color>, --color=<color>     Set the color of the boykisser
             -l=<color>, --list=<color>      List all available boykissers
             -p=<color>, --boykisser=<color>      Set the boykisser to display
+            -u=<style>, --uptime=<style>     Set the uptime style (long, short, seconds)
+            -d=<format>, --date-format=<format>     Set the strftime format of the date/time
+            --command=<label>:<command>     Add a line showing the output of a command
+            --file=<label>:<path>     Add a line showing the contents of a file
+            --command-timeout=<ms>     Set how long custom commands may run (default 1000)
+            --cpu-temp     Show the CPU temperature on the CPU line
+            --cpu-sparkline     Show the usage of every core after the CPU usage
+            --init-services     Show how many services the init system runs
+            --modules=<id>,<id>...     Set which modules to show and in which order
+            --list-modules     List all available modules
+            --theme=<name>     Set the theme, by name or path of a .theme file
+            --list-themes     List all available themes
+            --separator=<text>     Set what separates labels from values (default \" : \")
+            --indent=<n>     Set the spaces in front of every line (default 4)
+            --label-width=<n>     Set the width labels are padded to (default auto)
+            --label-case=<case>     Set the label casing (none, upper, lower, title)
+            --value-color=<color>     Set the color of values
+            --check-art     Check all boykissers for problems and print their size
+            --mirror     Mirror the boykisser horizontally
+            --flip     Flip the boykisser upside down
+            --scale=<factor>     Shrink the boykisser, e.g. 0.5 for half its size or 0.75
+            --max-height=<lines>     Cut the boykisser off after <lines> lines
+            --animate     Play animated boykissers until Ctrl-C is pressed
+            --watch[=<secs>]     Keep refreshing changing values until Ctrl-C (default every 2s)
+            --record-commands=<dir>     Save the output of every system command to <dir>
+            --image=<path>     Draw a PNG or JPEG image instead of a boykisser
+            --image-protocol=<protocol>     Set how the image is drawn (auto, blocks, kitty, sixel, iterm)
         ");
         
         std::process::exit(0);
@@ -57,7 +159,19 @@ impl Arguments {
         std::process::exit(0);
     }
 
-    fn print_err(err: &str) {
+    fn print_themes() {
+        println!("Available themes:");
+        for (theme, user) in Theme::list() {
+            match user {
+                true => println!("    {} (user)", theme),
+                false => println!("    {}", theme),
+            }
+        }
+
+        std::process::exit(0);
+    }
+
+    pub fn print_err(err: &str) {
         println!("Error: {}", err);
         println!("Usage: boykisserfetch [OPTION]...");
         println!("Try 'boykisserfetch --help' for more information.");
@@ -74,20 +188,185 @@ impl Arguments {
         args[1].to_string()
     }
 
+    // Falls back to the distro's accent color, then white, when neither -c
+    // nor the theme set one. Separate from `parse` so --record-commands
+    // already records the commands looking the accent up runs.
+    pub fn resolve_color(&mut self) {
+        #[cfg(feature = "host")]
+        if self.color.is_empty() {
+            self.color = crate::system::distro::get_accent_color().unwrap_or_default();
+        }
+
+        if self.color.is_empty() {
+            self.color = String::from("white");
+        }
+    }
+
     pub fn parse() -> Arguments {
         let mut args = Arguments {
             help: false,
             list: false,
             color: String::from(""),
-            boykisser: String::from("")
+            color_given: false,
+            boykisser: String::from(""),
+            uptime: String::from("long"),
+            date_format: String::from("%Y-%m-%d %H:%M:%S"),
+            custom: Vec::new(),
+            command_timeout: 1000,
+            cpu_temp: false,
+            cpu_sparkline: false,
+            init_services: false,
+            modules: Vec::new(),
+            list_modules: false,
+            record_commands: None,
+            image: None,
+            image_protocol: String::from("auto"),
+            animate: false,
+            watch: None,
+            mirror: false,
+            flip: false,
+            scale: 1.0,
+            max_height: None,
+            check_art: false,
+            theme: Theme::default(),
+            list_themes: false
         };
 
+        let mut theme = String::from("default");
+        // Theme keys set on the command line, applied over the theme.
+        let mut overrides: Vec<(&str, String)> = Vec::new();
+
         let args_vec: Vec<String> = std::env::args().collect();
 
         args_vec.into_iter().for_each(|arg| {
             match arg {
                 arg if arg == "--help" || arg == "-h" => args.help = true,
 
+                // Matched first, their values may contain "-c" or "-b".
+                arg if arg.starts_with("--date-format=") || arg.starts_with("-d=") => {
+                    args.date_format = arg.split_once('=').unwrap().1.to_string();
+                },
+
+                arg if arg.starts_with("--command=") => {
+                    args.custom.push(
+                        Self::validate_custom(arg.split_once('=').unwrap().1, false)
+                    );
+                },
+
+                arg if arg.starts_with("--file=") => {
+                    args.custom.push(
+                        Self::validate_custom(arg.split_once('=').unwrap().1, true)
+                    );
+                },
+
+                arg if arg.starts_with("--modules=") => {
+                    args.modules = Self::get_args(arg)
+                        .split(',')
+                        .map(|id| id.trim().to_string())
+                        .filter(|id| !id.is_empty())
+                        .collect();
+                },
+
+                arg if arg == "--list-modules" => args.list_modules = true,
+
+                arg if arg == "--animate" => args.animate = true,
+
+                arg if arg == "--check-art" => args.check_art = true,
+
+                arg if arg.starts_with("--theme=") => theme = arg.split_once('=').unwrap().1.to_string(),
+
+                arg if arg == "--list-themes" => args.list_themes = true,
+
+                // Before --color, these contain "-c" or may contain "=".
+                arg if arg.starts_with("--separator=") => {
+                    overrides.push(("separator", arg.split_once('=').unwrap().1.to_string()));
+                },
+
+                arg if arg.starts_with("--indent=") => {
+                    overrides.push(("indent", Self::validate_number(Self::get_args(arg), "indent")));
+                },
+
+                arg if arg.starts_with("--label-width=") => {
+                    let width = match Self::get_args(arg) {
+                        auto if auto == "auto" => auto,
+                        width => Self::validate_number(width, "label width"),
+                    };
+
+                    overrides.push(("label_width", width));
+                },
+
+                arg if arg.starts_with("--label-case=") => {
+                    overrides.push(("label_case", Self::validate_label_case(Self::get_args(arg))));
+                },
+
+                arg if arg.starts_with("--value-color=") => {
+                    overrides.push(("value", Self::validate_color(Self::get_args(arg))));
+                },
+
+                arg if arg == "--cpu-temp" => args.cpu_temp = true,
+
+                arg if arg == "--cpu-sparkline" => args.cpu_sparkline = true,
+
+                arg if arg == "--init-services" => args.init_services = true,
+
+                arg if arg == "--mirror" => args.mirror = true,
+
+                arg if arg == "--flip" => args.flip = true,
+
+                arg if arg.starts_with("--scale=") => {
+                    args.scale = match Self::get_args(arg).parse::<f64>() {
+                        Ok(scale) if scale > 0.0 && scale <= 1.0 => scale,
+                        _ => {
+                            Self::print_err("Invalid scale provided, expected a number up to 1.");
+                            std::process::exit(1);
+                        }
+                    };
+                },
+
+                arg if arg.starts_with("--max-height=") => {
+                    args.max_height = match Self::get_args(arg).parse::<usize>() {
+                        Ok(height) => Some(height),
+                        Err(_) => {
+                            Self::print_err("Invalid max height provided.");
+                            std::process::exit(1);
+                        }
+                    };
+                },
+
+                arg if arg == "--watch" => args.watch = Some(WATCH_INTERVAL),
+
+                arg if arg.starts_with("--watch=") => {
+                    args.watch = match Self::get_args(arg).parse::<f64>() {
+                        Ok(secs) if secs > 0.0 && secs.is_finite() => Some(secs),
+                        _ => {
+                            Self::print_err("Invalid watch interval provided.");
+                            std::process::exit(1);
+                        }
+                    };
+                },
+
+                arg if arg.starts_with("--image=") => {
+                    args.image = Some(arg.split_once('=').unwrap().1.to_string());
+                },
+
+                arg if arg.starts_with("--image-protocol=") => {
+                    args.image_protocol = Self::get_args(arg);
+                },
+
+                arg if arg.starts_with("--record-commands=") => {
+                    args.record_commands = Some(Self::get_args(arg));
+                },
+
+                arg if arg.starts_with("--command-timeout=") => {
+                    args.command_timeout = match Self::get_args(arg).parse::<u64>() {
+                        Ok(timeout) => timeout,
+                        Err(_) => {
+                            Self::print_err("Invalid command timeout provided.");
+                            std::process::exit(1);
+                        }
+                    };
+                },
+
                 arg if arg.contains("--color") || arg.contains("-c") => {
                     args.color = Self::validate_color(
                         Self::get_args(arg)
@@ -100,16 +379,42 @@ impl Arguments {
                     );
                 },
 
+                arg if arg.starts_with("--uptime=") || arg.starts_with("-u=") => {
+                    args.uptime = Self::validate_uptime(
+                        Self::get_args(arg)
+                    );
+                },
+
                 arg if arg == "--list" || arg == "-l" => args.list = true,
                 _ => ()
             }
         });
 
-        if args.color == "" {
-            args.color = String::from("white");
+        if args.list_themes {
+            Self::print_themes();
+        }
+
+        args.theme = Self::validate_theme(&theme);
+
+        // Validated while parsing, so this can't fail.
+        for (key, value) in overrides {
+            let _ = args.theme.set(key, &value);
         }
 
-        if args.boykisser == "" {
+        // The theme's colors and boykisser count as given, but not over -c and -b.
+        if args.color.is_empty() {
+            args.color = args.theme.primary.clone().unwrap_or_default();
+        }
+
+        if args.boykisser.is_empty() {
+            if let Some(boykisser) = args.theme.boykisser.clone() {
+                args.boykisser = Self::validate_boykisser(boykisser);
+            }
+        }
+
+        args.color_given = !args.color.is_empty();
+
+        if args.boykisser.is_empty() {
             args.boykisser = String::from("howyoulook");
         }
 
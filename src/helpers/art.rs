@@ -0,0 +1,498 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::colors::COLORS;
+
+// Number of ${c1}..${c6} color slots an art file can use.
+pub const SLOTS: usize = 6;
+
+const HEADER: &str = "colors:";
+const FRAME: &str = "frame:";
+
+// Milliseconds a frame is shown when its marker doesn't say.
+pub const FRAME_DELAY: u64 = 100;
+
+const TAB_WIDTH: usize = 8;
+
+// A run of text drawn in one color slot, 0 being ${c1}.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment {
+    pub slot: usize,
+    pub text: String,
+}
+
+// Art files are plain text, optionally starting with a header naming the
+// default color of every slot and using ${cN} markers to switch slots:
+//
+//     colors: white bright_magenta cyan
+//     ${c1}⠀⣰⠛⢠${c2}⣿⣿${c1}⡀⠸
+//
+// A marker stays in effect across lines until the next one, files without
+// header and markers are drawn entirely in slot 1.
+//
+// Animated art has a `frame: <ms>` line before every frame, giving how long
+// it is shown. Still art is a single frame without one.
+#[derive(Clone, Debug, Default)]
+pub struct Art {
+    pub colors: Vec<Option<String>>,
+    pub frames: Vec<Frame>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub delay: u64,
+    pub lines: Vec<Vec<Segment>>,
+}
+
+// Characters swapped by `mirror` and `flip`, both ways.
+const MIRRORED: &[(char, char)] = &[
+    ('/', '\\'), ('(', ')'), ('{', '}'), ('[', ']'), ('<', '>'),
+    ('▌', '▐'), ('▘', '▝'), ('▖', '▗'), ('▛', '▜'), ('▙', '▟'), ('▚', '▞'),
+    ('╱', '╲'), ('┌', '┐'), ('└', '┘'), ('├', '┤'), ('╭', '╮'), ('╰', '╯'),
+];
+
+const FLIPPED: &[(char, char)] = &[
+    ('/', '\\'), ('^', 'v'),
+    ('▀', '▄'), ('▘', '▖'), ('▝', '▗'), ('▛', '▙'), ('▜', '▟'), ('▚', '▞'),
+    ('╱', '╲'), ('┌', '└'), ('┐', '┘'), ('┬', '┴'), ('╭', '╰'), ('╮', '╯'),
+];
+
+// Bit of every braille dot, by row and column.
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+// A cell as the 2x4 dots of a braille character.
+type Dots = [[bool; 2]; 4];
+
+fn swap(c: char, table: &[(char, char)]) -> char {
+    table.iter()
+        .find_map(|&(a, b)| match c {
+            c if c == a => Some(b),
+            c if c == b => Some(a),
+            _ => None,
+        })
+        .unwrap_or(c)
+}
+
+fn braille_dots(c: char) -> Option<Dots> {
+    let bits = (c as u32).checked_sub(0x2800).filter(|&bits| bits <= 0xff)?;
+    let mut dots = [[false; 2]; 4];
+
+    for (y, row) in BRAILLE_DOTS.iter().enumerate() {
+        for (x, bit) in row.iter().enumerate() {
+            dots[y][x] = bits & bit != 0;
+        }
+    }
+
+    Some(dots)
+}
+
+fn braille_char(dots: &Dots) -> char {
+    let mut bits = 0;
+
+    for (y, row) in BRAILLE_DOTS.iter().enumerate() {
+        for (x, bit) in row.iter().enumerate() {
+            if dots[y][x] {
+                bits |= bit;
+            }
+        }
+    }
+
+    char::from_u32(0x2800 + bits).unwrap_or(' ')
+}
+
+// Dots covered by any character, block elements by their quadrants and
+// other characters as fully set.
+fn cell_dots(c: char) -> Dots {
+    if let Some(dots) = braille_dots(c) {
+        return dots;
+    }
+
+    // Top left, top right, bottom left and bottom right.
+    let quadrants = match c {
+        ' ' => [false, false, false, false],
+        '▘' => [true, false, false, false],
+        '▝' => [false, true, false, false],
+        '▖' => [false, false, true, false],
+        '▗' => [false, false, false, true],
+        '▀' => [true, true, false, false],
+        '▄' => [false, false, true, true],
+        '▌' => [true, false, true, false],
+        '▐' => [false, true, false, true],
+        '▚' => [true, false, false, true],
+        '▞' => [false, true, true, false],
+        '▛' => [true, true, true, false],
+        '▜' => [true, true, false, true],
+        '▙' => [true, false, true, true],
+        '▟' => [false, true, true, true],
+        _ => [true, true, true, true],
+    };
+
+    let mut dots = [[false; 2]; 4];
+    for (y, row) in dots.iter_mut().enumerate() {
+        for (x, dot) in row.iter_mut().enumerate() {
+            *dot = quadrants[y / 2 * 2 + x];
+        }
+    }
+
+    dots
+}
+
+fn mirror_char(c: char) -> char {
+    match braille_dots(c) {
+        Some(dots) => braille_char(&dots.map(|[left, right]| [right, left])),
+        None => swap(c, MIRRORED),
+    }
+}
+
+fn flip_char(c: char) -> char {
+    match braille_dots(c) {
+        Some([a, b, c, d]) => braille_char(&[d, c, b, a]),
+        None => swap(c, FLIPPED),
+    }
+}
+
+// A line as its characters and their slots.
+fn cells(line: &[Segment]) -> Vec<(char, usize)> {
+    line.iter()
+        .flat_map(|segment| segment.text.chars().map(|c| (c, segment.slot)))
+        .collect()
+}
+
+fn segments(cells: &[(char, usize)]) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+
+    for &(c, slot) in cells {
+        match segments.last_mut() {
+            Some(segment) if segment.slot == slot => segment.text.push(c),
+            _ => segments.push(Segment { slot, text: c.to_string() }),
+        }
+    }
+
+    segments
+}
+
+fn parse_marker(text: &str) -> Option<(usize, usize)> {
+    let rest = text.strip_prefix("${c")?;
+    let end = rest.find('}')?;
+    let slot = rest[..end].parse::<usize>().ok()?;
+
+    match (1..=SLOTS).contains(&slot) {
+        true => Some((slot - 1, end + 4)),
+        false => None,
+    }
+}
+
+// Columns `text` takes up in a terminal.
+pub fn display_width(text: &str) -> usize {
+    text.width()
+}
+
+// Unifies line endings, strips trailing whitespace from every line and
+// drops empty lines at the end of the file.
+pub fn normalize(text: &str) -> String {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let lines = text.split('\n').map(str::trim_end).collect::<Vec<&str>>();
+    let end = lines.iter().rposition(|line| !line.is_empty()).map_or(0, |i| i + 1);
+
+    lines[..end].join("\n")
+}
+
+// Problems with the art file `text`, by line. Those fixed by `normalize`
+// are listed too, so the file itself can be cleaned up.
+pub fn check(text: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if text.contains('\r') {
+        problems.push(String::from("CR line endings"));
+    }
+
+    let mut lines = text.split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .enumerate();
+
+    if let Some(header) = text.strip_prefix(HEADER) {
+        let header = header.lines().next().unwrap_or_default();
+        lines.next();
+
+        for name in header.split_whitespace() {
+            if !COLORS.iter().any(|(color, _)| *color == name) {
+                problems.push(format!("line 1: unknown color {}", name));
+            }
+        }
+    }
+
+    for (i, line) in lines {
+        let problem = |message: String| format!("line {}: {}", i + 1, message);
+
+        if let Some(delay) = line.strip_prefix(FRAME).map(str::trim) {
+            if !delay.is_empty() && delay.parse::<u64>().is_err() {
+                problems.push(problem(format!("invalid frame delay {}", delay)));
+            }
+
+            continue;
+        }
+
+        if line.ends_with(char::is_whitespace) {
+            problems.push(problem(String::from("trailing whitespace")));
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find("${") {
+            rest = &rest[start..];
+
+            match parse_marker(rest) {
+                Some((_, len)) => rest = &rest[len..],
+                None => {
+                    let end = rest.find('}').map_or(rest.len(), |end| end + 1);
+                    problems.push(problem(format!("unknown marker {}", &rest[..end])));
+                    rest = &rest[end..];
+                }
+            }
+        }
+
+        for c in line.chars() {
+            let message = match c {
+                '\t' => String::from("tab"),
+                c if c.is_control() => format!("control character U+{:04X}", c as u32),
+                c if c.width() == Some(0) => format!("zero-width character U+{:04X}", c as u32),
+                c if c.width() != c.width_cjk() => {
+                    format!("U+{:04X} is wider in some terminals", c as u32)
+                },
+                _ => continue,
+            };
+
+            problems.push(problem(message));
+        }
+    }
+
+    problems.dedup();
+    problems
+}
+
+fn parse_line(line: &str, slot: &mut usize) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut column = 0;
+    let mut rest = line;
+
+    while !rest.is_empty() {
+        if let Some((next, len)) = parse_marker(rest) {
+            if !text.is_empty() {
+                segments.push(Segment { slot: *slot, text: std::mem::take(&mut text) });
+            }
+
+            *slot = next;
+            rest = &rest[len..];
+            continue;
+        }
+
+        let c = rest.chars().next().unwrap_or_default();
+        rest = &rest[c.len_utf8()..];
+
+        // Tabs are expanded to the next tab stop.
+        if c == '\t' {
+            let spaces = TAB_WIDTH - column % TAB_WIDTH;
+            text.push_str(&" ".repeat(spaces));
+            column += spaces;
+            continue;
+        }
+
+        text.push(c);
+        column += c.width().unwrap_or(0);
+    }
+
+    if !text.is_empty() || segments.is_empty() {
+        segments.push(Segment { slot: *slot, text });
+    }
+
+    segments
+}
+
+impl Art {
+    pub fn parse(text: &str) -> Art {
+        let text = normalize(text);
+        let mut colors = vec![None; SLOTS];
+        let mut body = text.as_str();
+
+        if let Some(header) = text.strip_prefix(HEADER) {
+            let (header, rest) = header.split_once('\n').unwrap_or((header, ""));
+
+            for (slot, name) in header.split_whitespace().take(SLOTS).enumerate() {
+                if COLORS.iter().any(|(color, _)| *color == name) {
+                    colors[slot] = Some(name.to_string());
+                }
+            }
+
+            body = rest;
+        }
+
+        let mut frames: Vec<Frame> = Vec::new();
+        let mut slot = 0;
+
+        for line in body.split('\n') {
+            if let Some(delay) = line.strip_prefix(FRAME) {
+                // Every frame starts in slot 1.
+                slot = 0;
+                frames.push(Frame {
+                    delay: delay.trim().parse().unwrap_or(FRAME_DELAY),
+                    lines: Vec::new(),
+                });
+                continue;
+            }
+
+            if frames.is_empty() {
+                frames.push(Frame { delay: FRAME_DELAY, lines: Vec::new() });
+            }
+
+            if let Some(frame) = frames.last_mut() {
+                frame.lines.push(parse_line(line, &mut slot));
+            }
+        }
+
+        Art { colors, frames }
+    }
+
+    pub fn height(&self) -> usize {
+        self.frames.iter().map(|frame| frame.lines.len()).max().unwrap_or(0)
+    }
+
+    pub fn width(&self) -> usize {
+        self.frames.iter()
+            .flat_map(|frame| frame.lines.iter())
+            .map(|line| line.iter().map(|segment| display_width(&segment.text)).sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.frames.len() > 1
+    }
+
+    fn map_frames(&self, f: impl Fn(&Frame) -> Vec<Vec<Segment>>) -> Art {
+        Art {
+            colors: self.colors.clone(),
+            frames: self.frames.iter()
+                .map(|frame| Frame { delay: frame.delay, lines: f(frame) })
+                .collect(),
+        }
+    }
+
+    // Swaps left and right, lines are padded to the art's width first so
+    // they stay aligned on the right.
+    pub fn mirror(&self) -> Art {
+        let width = self.width();
+
+        self.map_frames(|frame| {
+            frame.lines.iter()
+                .map(|line| {
+                    let mut cells = cells(line);
+                    let slot = cells.last().map_or(0, |&(_, slot)| slot);
+                    cells.resize(width, (' ', slot));
+
+                    let cells = cells.iter()
+                        .rev()
+                        .map(|&(c, slot)| (mirror_char(c), slot))
+                        .collect::<Vec<_>>();
+
+                    segments(&cells)
+                })
+                .collect()
+        })
+    }
+
+    // Swaps top and bottom.
+    pub fn flip(&self) -> Art {
+        let height = self.height();
+
+        self.map_frames(|frame| {
+            let mut lines = frame.lines.clone();
+            lines.resize(height, Vec::new());
+
+            lines.iter()
+                .rev()
+                .map(|line| {
+                    let cells = cells(line).iter()
+                        .map(|&(c, slot)| (flip_char(c), slot))
+                        .collect::<Vec<_>>();
+
+                    segments(&cells)
+                })
+                .collect()
+        })
+    }
+
+    // Shrinks the art by `factor` (0.5 halves it, 0.75 takes it to three
+    // quarters) by resampling its braille dots: a dot is set when any of the
+    // dots it covers is. A character takes the slot of its first non-empty
+    // cell.
+    pub fn scale(&self, factor: f64) -> Art {
+        if factor >= 1.0 {
+            return self.clone();
+        }
+
+        // Scaled dot or cell `i` starts at source dot or cell `start(i)`. The
+        // epsilon keeps e.g. 3 / (1 / 3) from landing right below 9.
+        let start = |i: usize| (i as f64 / factor + 1e-9).floor() as usize;
+        let span = |i: usize| start(i)..start(i + 1).max(start(i) + 1);
+        let scaled = |len: usize| (len as f64 * factor - 1e-9).ceil() as usize;
+
+        self.map_frames(|frame| {
+            let grid = frame.lines.iter().map(|line| cells(line)).collect::<Vec<_>>();
+            let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+            let cell = |x: usize, y: usize| grid.get(y).and_then(|line| line.get(x)).copied();
+
+            (0..scaled(grid.len()))
+                .map(|row| {
+                    let line = (0..scaled(width))
+                        .map(|col| {
+                            let mut dots = [[false; 2]; 4];
+                            let mut slot = None;
+
+                            for (dy, dot_row) in dots.iter_mut().enumerate() {
+                                for (dx, dot) in dot_row.iter_mut().enumerate() {
+                                    for y in span(row * 4 + dy) {
+                                        for x in span(col * 2 + dx) {
+                                            let Some((c, cell_slot)) = cell(x / 2, y / 4) else { continue };
+
+                                            if cell_dots(c)[y % 4][x % 2] {
+                                                *dot = true;
+                                                slot = slot.or(Some(cell_slot));
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            let slot = slot.or(cell(start(col), start(row)).map(|(_, slot)| slot));
+                            (braille_char(&dots), slot.unwrap_or(0))
+                        })
+                        .collect::<Vec<_>>();
+
+                    segments(&line)
+                })
+                .collect()
+        })
+    }
+
+    // Keeps at most `height` lines of every frame.
+    pub fn crop(&self, height: usize) -> Art {
+        self.map_frames(|frame| frame.lines.iter().take(height).cloned().collect())
+    }
+
+    // Color of every slot. `primary` replaces slot 1 when `force` is set or
+    // the header doesn't name one, slots without a color use slot 1's.
+    pub fn palette(&self, primary: &str, force: bool) -> Vec<String> {
+        let first = match (&self.colors[0], force) {
+            (Some(color), false) => color.clone(),
+            _ => primary.to_string(),
+        };
+
+        let mut palette = vec![first.clone()];
+        palette.extend(
+            self.colors.iter()
+                .skip(1)
+                .map(|color| color.clone().unwrap_or_else(|| first.clone()))
+        );
+
+        palette
+    }
+}
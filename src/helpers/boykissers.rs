@@ -1,40 +1,43 @@
 use std::collections::HashMap;
+use super::art::Art;
+
 pub struct boykisser {
-    pub text: String,
+    pub art: Art,
     pub lines: u16
 }
 
-pub fn get_boykisser(name: String) -> Option<boykisser> {
-    let boykissers: HashMap<&str, Vec<u8>> = HashMap::from([
-        ("withhighthighs", include_bytes!("../../boykissers/withhighthighs.txt").to_vec()),
-        ("howyoulook", include_bytes!("../../boykissers/howyoulook.txt").to_vec()),
+// Contents of the built-in art file `name`.
+pub fn get_source(name: &str) -> Option<&'static str> {
+    let boykissers: HashMap<&str, &'static str> = HashMap::from([
+        ("withhighthighs", include_str!("../../boykissers/withhighthighs.txt")),
+        ("howyoulook", include_str!("../../boykissers/howyoulook.txt")),
         #[cfg(target_os = "linux")]
-        ("ahhhaah", include_bytes!("../../boykissers/ahhhaah.txt").to_vec()),
-        ("cute", include_bytes!("../../boykissers/cute.txt").to_vec()),
-        ("cutereversed", include_bytes!("../../boykissers/cutereversed.txt").to_vec()),
-        ("cutie", include_bytes!("../../boykissers/cutie.txt").to_vec()),
-        ("sad", include_bytes!("../../boykissers/sad.txt").to_vec()),
-        ("sowhat", include_bytes!("../../boykissers/sowhat.txt").to_vec()),
-        ("squinting", include_bytes!("../../boykissers/squinting.txt").to_vec()),
-        ("thesilly_large", include_bytes!("../../boykissers/thesilly_large.txt").to_vec()),
-        ("thesilly", include_bytes!("../../boykissers/thesilly.txt").to_vec()),
-        ("typing", include_bytes!("../../boykissers/typing.txt").to_vec()),
-        ("withhighthighsalt", include_bytes!("../../boykissers/withhighthighsalt.txt").to_vec()),
-        ("yayyy", include_bytes!("../../boykissers/yayyy.txt").to_vec()),
-        ("yippie", include_bytes!("../../boykissers/yippie.txt").to_vec()),
-        ("youafurry", include_bytes!("../../boykissers/youafurry.txt").to_vec()),
-        ("youlikeboys", include_bytes!("../../boykissers/youlikeboys.txt").to_vec()),
-        ("youlikeboysfullbody", include_bytes!("../../boykissers/youlikeboysfullbody.txt").to_vec()),
+        ("ahhhaah", include_str!("../../boykissers/ahhhaah.txt")),
+        ("cute", include_str!("../../boykissers/cute.txt")),
+        ("cutereversed", include_str!("../../boykissers/cutereversed.txt")),
+        ("cutie", include_str!("../../boykissers/cutie.txt")),
+        ("sad", include_str!("../../boykissers/sad.txt")),
+        ("sowhat", include_str!("../../boykissers/sowhat.txt")),
+        ("squinting", include_str!("../../boykissers/squinting.txt")),
+        ("thesilly_large", include_str!("../../boykissers/thesilly_large.txt")),
+        ("thesilly", include_str!("../../boykissers/thesilly.txt")),
+        ("typing", include_str!("../../boykissers/typing.txt")),
+        ("withhighthighsalt", include_str!("../../boykissers/withhighthighsalt.txt")),
+        ("yayyy", include_str!("../../boykissers/yayyy.txt")),
+        ("yippie", include_str!("../../boykissers/yippie.txt")),
+        ("youafurry", include_str!("../../boykissers/youafurry.txt")),
+        ("youlikeboys", include_str!("../../boykissers/youlikeboys.txt")),
+        ("youlikeboysfullbody", include_str!("../../boykissers/youlikeboysfullbody.txt")),
     ]);
 
-    let boykisser = String::from_utf8(
-            boykissers.get(
-            name.as_str()
-        ).unwrap().to_vec()
-    ).unwrap();
+    boykissers.get(name).copied()
+}
+
+pub fn get_boykisser(name: String) -> Option<boykisser> {
+    let art = Art::parse(get_source(&name)?);
 
     Some(boykisser {
-        text: boykisser.clone(),
-        lines: boykisser.split("\n").count() as u16
+        lines: art.height() as u16,
+        art
     })
 }
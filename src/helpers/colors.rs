@@ -64,6 +64,16 @@ fn enable_ansi_support() {
     }
 }
 
+// The bold variant of `color`, if there is one.
+pub fn bold(color: &str) -> String {
+    let bold = format!("{}_bold", color);
+
+    match COLORS.iter().any(|(name, _)| *name == bold) {
+        true => bold,
+        false => color.to_string(),
+    }
+}
+
 pub fn print(text: &str, inline: bool, color: &str) {
     let color = COLORS.iter().find(
         |(name, _)| name == &color
@@ -0,0 +1,242 @@
+use std::{
+    cell::RefCell,
+    io::Read,
+    path::PathBuf,
+    process::{Command, Stdio},
+    rc::Rc,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+// How long output may take to arrive once a command run with a timeout
+// exited. It's usually there already, but a child the command left running
+// in the background would keep the pipe open forever.
+const OUTPUT_GRACE: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RunError {
+    Failed,
+    TimedOut,
+}
+
+thread_local! {
+    static RUNNER: RefCell<Rc<dyn CommandRunner>> = RefCell::new(Rc::new(SystemRunner));
+}
+
+// Everything the collectors shell out to goes through a runner, so tests
+// can swap the real tools for canned output.
+pub trait CommandRunner {
+    // Returns stdout, or None if the program is missing or failed.
+    fn run(&self, program: &str, args: &[&str]) -> Option<String>;
+
+    // Returns stdout even if the program failed, unless it ran longer than
+    // `timeout`. Runners without real processes answer like `run`.
+    fn run_timeout(
+        &self,
+        program: &str,
+        args: &[&str],
+        _timeout: Duration
+    ) -> Result<String, RunError> {
+        self.run(program, args).ok_or(RunError::Failed)
+    }
+
+    // Runs `f` with this runner as the current one on this thread.
+    fn enter<T, F: FnOnce() -> T>(self, f: F) -> T where Self: Sized + 'static {
+        struct Restore(Rc<dyn CommandRunner>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                RUNNER.with(|runner| *runner.borrow_mut() = self.0.clone());
+            }
+        }
+
+        let _restore = Restore(RUNNER.with(|runner| runner.replace(Rc::new(self))));
+
+        f()
+    }
+}
+
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, program: &str, args: &[&str]) -> Option<String> {
+        let output = Command::new(program)
+            .args(args)
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        Some(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    fn run_timeout(
+        &self,
+        program: &str,
+        args: &[&str],
+        timeout: Duration
+    ) -> Result<String, RunError> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|_| RunError::Failed)?;
+
+        // stdout is drained on its own thread so a chatty command can't block
+        // on a full pipe while we wait for it to exit.
+        let mut stdout = child.stdout.take().ok_or(RunError::Failed)?;
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            let mut output = String::new();
+            let _ = stdout.read_to_string(&mut output);
+            let _ = sender.send(output);
+        });
+
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            match child.try_wait() {
+                Ok(Some(_)) => break,
+                Ok(None) if Instant::now() < deadline => {
+                    std::thread::sleep(Duration::from_millis(10));
+                },
+                _ => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(RunError::TimedOut);
+                }
+            }
+        }
+
+        receiver.recv_timeout(OUTPUT_GRACE).map_err(|_| RunError::Failed)
+    }
+}
+
+// Name of the fixture file holding the output of `program args`, e.g.
+// "wmic cpu get name" is stored as "wmic_cpu_get_name".
+pub fn fixture_name(program: &str, args: &[&str]) -> String {
+    let command = std::iter::once(program)
+        .chain(args.iter().copied())
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    let name = command.chars()
+        .map(|c| match c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+            true => c,
+            false => '_',
+        })
+        .collect::<String>();
+
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("_")
+}
+
+// Answers from a directory of recorded outputs, commands without a
+// recording behave as if the program wasn't installed.
+pub struct ReplayRunner {
+    dir: PathBuf,
+}
+
+impl ReplayRunner {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> ReplayRunner {
+        ReplayRunner { dir: dir.into() }
+    }
+}
+
+impl CommandRunner for ReplayRunner {
+    fn run(&self, program: &str, args: &[&str]) -> Option<String> {
+        std::fs::read_to_string(self.dir.join(fixture_name(program, args))).ok()
+    }
+}
+
+// Runs the real commands and saves their output in the layout
+// `ReplayRunner` reads.
+pub struct RecordingRunner {
+    dir: PathBuf,
+}
+
+impl RecordingRunner {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> RecordingRunner {
+        RecordingRunner { dir: dir.into() }
+    }
+}
+
+impl CommandRunner for RecordingRunner {
+    fn run(&self, program: &str, args: &[&str]) -> Option<String> {
+        let output = SystemRunner.run(program, args)?;
+
+        if std::fs::create_dir_all(&self.dir).is_ok() {
+            let _ = std::fs::write(self.dir.join(fixture_name(program, args)), &output);
+        }
+
+        Some(output)
+    }
+
+    fn run_timeout(
+        &self,
+        program: &str,
+        args: &[&str],
+        timeout: Duration
+    ) -> Result<String, RunError> {
+        let output = SystemRunner.run_timeout(program, args, timeout)?;
+
+        if std::fs::create_dir_all(&self.dir).is_ok() {
+            let _ = std::fs::write(self.dir.join(fixture_name(program, args)), &output);
+        }
+
+        Ok(output)
+    }
+}
+
+pub fn run(program: &str, args: &[&str]) -> Option<String> {
+    let runner = RUNNER.with(|runner| runner.borrow().clone());
+
+    runner.run(program, args)
+}
+
+pub fn run_timeout(program: &str, args: &[&str], timeout: Duration) -> Result<String, RunError> {
+    let runner = RUNNER.with(|runner| runner.borrow().clone());
+
+    runner.run_timeout(program, args, timeout)
+}
+
+// Rows of a `wmic ... get <columns>` table, without the header.
+pub fn wmic_rows(output: &str) -> Vec<String> {
+    output.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .skip(1)
+        .map(str::to_string)
+        .collect()
+}
+
+// Every value of `key` in `wmic ... /format:value` output ("Key=Value").
+pub fn wmic_values(output: &str, key: &str) -> Vec<String> {
+    output.lines()
+        .filter_map(|line| line.trim().split_once('='))
+        .filter(|(name, _)| *name == key)
+        .map(|(_, value)| value.trim().to_string())
+        .collect()
+}
+
+// Data of every `name` value in `reg query` output, where each value is
+// printed as "    Name    REG_SZ    Data with spaces".
+pub fn reg_values(output: &str, name: &str) -> Vec<String> {
+    output.lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let rest = line.strip_prefix(name)?;
+            let rest = rest.trim_start().strip_prefix("REG_")?;
+            let (_, data) = rest.split_once(char::is_whitespace)?;
+
+            Some(data.trim().to_string())
+        })
+        .collect()
+}
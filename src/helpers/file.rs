@@ -1,12 +1,96 @@
-#[cfg(target_os = "linux")]
-use std::{fs::File, io::Read};
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+};
 
-#[cfg(target_os = "linux")]
-pub fn file_open(path: &str) -> String {
-    let mut temp_buf: String = String::new();
+thread_local! {
+    static ROOT: RefCell<SysRoot> = RefCell::new(SysRoot::new("/"));
+}
+
+// Directory the collectors resolve absolute paths such as "/proc/cpuinfo"
+// against. It is "/" unless a test enters a fixture directory.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SysRoot {
+    root: PathBuf,
+}
+
+impl SysRoot {
+    pub fn new<P: Into<PathBuf>>(root: P) -> SysRoot {
+        SysRoot { root: root.into() }
+    }
+
+    pub fn current() -> SysRoot {
+        ROOT.with(|root| root.borrow().clone())
+    }
+
+    // Runs `f` with this root as the current one on this thread.
+    pub fn enter<T, F: FnOnce() -> T>(&self, f: F) -> T {
+        struct Restore(SysRoot);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                ROOT.with(|root| *root.borrow_mut() = self.0.clone());
+            }
+        }
+
+        let _restore = Restore(ROOT.with(|root| root.replace(self.clone())));
+
+        f()
+    }
+
+    pub fn path(&self, path: &str) -> PathBuf {
+        self.root.join(path.trim_start_matches('/'))
+    }
 
-    let mut file = File::open(path).unwrap();
-    file.read_to_string(&mut temp_buf).unwrap();
+    pub fn read(&self, path: &str) -> Option<String> {
+        std::fs::read_to_string(self.path(path)).ok()
+    }
 
-    temp_buf
-}
\ No newline at end of file
+    pub fn read_link(&self, path: &str) -> Option<PathBuf> {
+        std::fs::read_link(self.path(path)).ok()
+    }
+
+    pub fn exists(&self, path: &str) -> bool {
+        self.path(path).exists()
+    }
+
+    // Sorted paths of the directory's entries, relative to the root like
+    // the `path` that was passed in.
+    pub fn read_dir(&self, path: &str) -> Vec<String> {
+        let mut entries = match std::fs::read_dir(self.path(path)) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| {
+                    Path::new(path)
+                        .join(entry.file_name())
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect::<Vec<String>>(),
+            Err(_) => Vec::new(),
+        };
+
+        entries.sort();
+        entries
+    }
+}
+
+pub fn read(path: &str) -> Option<String> {
+    SysRoot::current().read(path)
+}
+
+pub fn read_link(path: &str) -> Option<PathBuf> {
+    SysRoot::current().read_link(path)
+}
+
+pub fn exists(path: &str) -> bool {
+    SysRoot::current().exists(path)
+}
+
+pub fn read_dir(path: &str) -> Vec<String> {
+    SysRoot::current().read_dir(path)
+}
+
+pub fn file_open(path: &str) -> String {
+    read(path).unwrap_or_default()
+}
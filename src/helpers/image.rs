@@ -0,0 +1,277 @@
+use image::{imageops::FilterType, RgbaImage};
+
+pub const PROTOCOLS: [&str; 5] = ["auto", "blocks", "kitty", "sixel", "iterm"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Protocol {
+    // "▀" with a truecolor foreground and background, works everywhere.
+    HalfBlocks,
+    Kitty,
+    Sixel,
+    Iterm,
+}
+
+impl Protocol {
+    pub fn from_name(name: &str) -> Option<Protocol> {
+        match name {
+            "auto" => Some(Protocol::detect()),
+            "blocks" => Some(Protocol::HalfBlocks),
+            "kitty" => Some(Protocol::Kitty),
+            "sixel" => Some(Protocol::Sixel),
+            "iterm" => Some(Protocol::Iterm),
+            _ => None,
+        }
+    }
+
+    // Terminals don't reliably answer capability queries, so this goes by
+    // the variables they set.
+    pub fn detect() -> Protocol {
+        let var = |name: &str| std::env::var(name).unwrap_or_default();
+        let term = var("TERM");
+
+        match var("TERM_PROGRAM").as_str() {
+            "ghostty" => return Protocol::Kitty,
+            "iTerm.app" | "WezTerm" | "mintty" => return Protocol::Iterm,
+            _ => (),
+        }
+
+        if !var("KITTY_WINDOW_ID").is_empty() || term == "xterm-kitty" {
+            return Protocol::Kitty;
+        }
+
+        if term.starts_with("foot") || term.contains("mlterm") || term.contains("sixel") {
+            return Protocol::Sixel;
+        }
+
+        Protocol::HalfBlocks
+    }
+}
+
+pub struct Picture {
+    pub cols: u16,
+    pub rows: u16,
+    pub protocol: Protocol,
+    image: RgbaImage,
+    bytes: Vec<u8>,
+}
+
+// Pixel size of one terminal cell, guessed when the terminal doesn't say.
+fn cell_size() -> (u32, u32) {
+    #[cfg(unix)]
+    // SAFETY: TIOCGWINSZ only writes into the winsize struct passed to it.
+    unsafe {
+        let mut size: libc::winsize = std::mem::zeroed();
+
+        if libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) == 0
+            && size.ws_xpixel > 0
+            && size.ws_ypixel > 0
+            && size.ws_col > 0
+            && size.ws_row > 0
+        {
+            return (
+                (size.ws_xpixel / size.ws_col) as u32,
+                (size.ws_ypixel / size.ws_row) as u32,
+            );
+        }
+    }
+
+    (8, 16)
+}
+
+impl Picture {
+    // Loads the image at `path` scaled to `rows` terminal rows.
+    pub fn load(path: &str, rows: u16, protocol: Protocol) -> Result<Picture, String> {
+        let bytes = std::fs::read(path).map_err(|err| format!("Can't read {}: {}.", path, err))?;
+        let image = image::load_from_memory(&bytes)
+            .map_err(|err| format!("Can't decode {}: {}.", path, err))?
+            .to_rgba8();
+
+        Ok(Picture::from_image(image, bytes, rows.max(1), protocol))
+    }
+
+    pub fn from_image(image: RgbaImage, bytes: Vec<u8>, rows: u16, protocol: Protocol) -> Picture {
+        let (cell_width, cell_height) = match protocol {
+            // Every cell holds two square pixels stacked on top of each other.
+            Protocol::HalfBlocks => (1, 2),
+            _ => cell_size(),
+        };
+
+        let height = rows as u32 * cell_height;
+        let width = (image.width() * height / image.height().max(1)).max(1);
+        let cols = width.div_ceil(cell_width).max(1) as u16;
+
+        Picture {
+            cols,
+            rows,
+            protocol,
+            image: image::imageops::resize(&image, width, height, FilterType::Triangle),
+            bytes,
+        }
+    }
+
+    // Draws the picture for the graphics protocols. Room for it is made
+    // first so the info printed next to it starts on the same row.
+    pub fn draw(&self) {
+        let sequence = match self.protocol {
+            Protocol::HalfBlocks => return,
+            Protocol::Kitty => self.kitty(),
+            Protocol::Sixel => self.sixel(),
+            Protocol::Iterm => self.iterm(),
+        };
+
+        print!("{}\u{1b}[{}A\u{1b}7{}\u{1b}8", "\n".repeat(self.rows as usize), self.rows, sequence);
+    }
+
+    // Prints the picture's part of `row`, rows below it are padded.
+    pub fn print_line(&self, row: u16) {
+        match self.protocol {
+            Protocol::HalfBlocks => print!("{}", self.half_block_line(row)),
+            _ => print!("\u{1b}[{}C", self.cols),
+        }
+    }
+
+    pub fn half_block_line(&self, row: u16) -> String {
+        let mut line = String::new();
+        let pixel = |x: u32, y: u32| match y < self.image.height() {
+            true => Some(self.image.get_pixel(x, y).0).filter(|pixel| pixel[3] >= 128),
+            false => None,
+        };
+
+        for x in 0..self.image.width() {
+            let (top, bottom) = (pixel(x, row as u32 * 2), pixel(x, row as u32 * 2 + 1));
+
+            match (top, bottom) {
+                (Some(top), Some(bottom)) => line.push_str(&format!(
+                    "\u{1b}[38;2;{};{};{}m\u{1b}[48;2;{};{};{}m▀",
+                    top[0], top[1], top[2], bottom[0], bottom[1], bottom[2]
+                )),
+                (Some(top), None) => line.push_str(&format!(
+                    "\u{1b}[49m\u{1b}[38;2;{};{};{}m▀", top[0], top[1], top[2]
+                )),
+                (None, Some(bottom)) => line.push_str(&format!(
+                    "\u{1b}[49m\u{1b}[38;2;{};{};{}m▄", bottom[0], bottom[1], bottom[2]
+                )),
+                (None, None) => line.push_str("\u{1b}[0m "),
+            }
+        }
+
+        line.push_str("\u{1b}[0m");
+        line
+    }
+
+    // https://sw.kovidgoyal.net/kitty/graphics-protocol/, raw RGBA sent in
+    // chunks of at most 4096 base64 bytes.
+    fn kitty(&self) -> String {
+        let data = base64(self.image.as_raw());
+        let chunks = data.as_bytes().chunks(4096).collect::<Vec<&[u8]>>();
+        let mut sequence = String::new();
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            let more = (i + 1 < chunks.len()) as u8;
+            let chunk = String::from_utf8_lossy(chunk);
+
+            match i {
+                0 => sequence.push_str(&format!(
+                    "\u{1b}_Ga=T,f=32,s={},v={},c={},r={},q=2,m={};{}\u{1b}\\",
+                    self.image.width(), self.image.height(), self.cols, self.rows, more, chunk
+                )),
+                _ => sequence.push_str(&format!("\u{1b}_Gm={};{}\u{1b}\\", more, chunk)),
+            }
+        }
+
+        sequence
+    }
+
+    // https://iterm2.com/documentation-images.html, the original file is
+    // sent and scaled by the terminal.
+    fn iterm(&self) -> String {
+        format!(
+            "\u{1b}]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{}\u{7}",
+            self.bytes.len(), self.cols, self.rows, base64(&self.bytes)
+        )
+    }
+
+    // Sixel with a fixed 6x6x6 color cube, transparent pixels are skipped.
+    fn sixel(&self) -> String {
+        let level = |value: u8| (value as u32 * 5 + 127) / 255;
+        let index = |pixel: &[u8; 4]| match pixel[3] >= 128 {
+            true => Some((level(pixel[0]) * 36 + level(pixel[1]) * 6 + level(pixel[2])) as usize),
+            false => None,
+        };
+
+        let mut sequence = String::from("\u{1b}P0;1;0q");
+        sequence.push_str(&format!("\"1;1;{};{}", self.image.width(), self.image.height()));
+
+        for color in 0..216 {
+            let percent = |value: usize| value * 100 / 5;
+            sequence.push_str(&format!(
+                "#{};2;{};{};{}",
+                color, percent(color / 36), percent(color / 6 % 6), percent(color % 6)
+            ));
+        }
+
+        for band in (0..self.image.height()).step_by(6) {
+            let mut rows = vec![vec![0u8; self.image.width() as usize]; 216];
+            let mut used = vec![false; 216];
+
+            for y in band..(band + 6).min(self.image.height()) {
+                for x in 0..self.image.width() {
+                    if let Some(color) = index(&self.image.get_pixel(x, y).0) {
+                        rows[color][x as usize] |= 1 << (y - band);
+                        used[color] = true;
+                    }
+                }
+            }
+
+            for color in (0..216).filter(|&color| used[color]) {
+                sequence.push_str(&format!("#{}", color));
+                sequence.push_str(&sixel_run_length(&rows[color]));
+                sequence.push('$');
+            }
+
+            sequence.push('-');
+        }
+
+        sequence.push_str("\u{1b}\\");
+        sequence
+    }
+}
+
+fn sixel_run_length(bits: &[u8]) -> String {
+    let mut encoded = String::new();
+    let mut i = 0;
+
+    while i < bits.len() {
+        let run = bits[i..].iter().take_while(|&&b| b == bits[i]).count();
+        let c = (bits[i] + 63) as char;
+
+        match run {
+            1..=3 => encoded.push_str(&c.to_string().repeat(run)),
+            _ => encoded.push_str(&format!("!{}{}", run, c)),
+        }
+
+        i += run;
+    }
+
+    encoded
+}
+
+pub fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let triple = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => encoded.push(ALPHABET[(triple >> (18 - i * 6) & 63) as usize] as char),
+                false => encoded.push('='),
+            }
+        }
+    }
+
+    encoded
+}
@@ -0,0 +1,60 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+// Set by Ctrl-C once `catch` replaced the default handler, so the
+// --animate and --watch loops can restore the terminal before exiting.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(unix)]
+extern "C" fn on_interrupt(_: libc::c_int) {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+#[cfg(unix)]
+pub fn catch() {
+    let handler = on_interrupt as extern "C" fn(libc::c_int) as libc::sighandler_t;
+
+    // SAFETY: the handler only stores into an atomic, which is signal safe.
+    unsafe {
+        libc::signal(libc::SIGINT, handler);
+        libc::signal(libc::SIGTERM, handler);
+    }
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn on_interrupt(_: u32) -> i32 {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+    1
+}
+
+#[cfg(windows)]
+pub fn catch() {
+    // SAFETY: the handler only stores into an atomic.
+    unsafe {
+        kernel32::SetConsoleCtrlHandler(Some(on_interrupt), 1);
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+pub fn catch() {}
+
+pub fn interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+// Sleeps for `delay`, returning early with false once Ctrl-C was pressed.
+pub fn wait(delay: Duration) -> bool {
+    let start = Instant::now();
+
+    while start.elapsed() < delay {
+        if INTERRUPTED.load(Ordering::SeqCst) {
+            return false;
+        }
+
+        std::thread::sleep(Duration::from_millis(10).min(delay.saturating_sub(start.elapsed())));
+    }
+
+    !INTERRUPTED.load(Ordering::SeqCst)
+}
@@ -1,6 +1,16 @@
+pub mod animation;
 pub mod arguments;
+pub mod art;
+pub mod command;
 pub mod file;
+#[cfg(feature = "image")]
+pub mod image;
+pub mod interrupt;
 pub mod colors;
 pub mod print;
 pub mod paths;
-pub mod boykissers;
\ No newline at end of file
+pub mod boykissers;
+pub mod process;
+pub mod theme;
+pub mod time;
+pub mod tty;
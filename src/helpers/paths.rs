@@ -21,3 +21,17 @@ pub fn get_boykissers() -> Vec<&'static str> {
         "youlikeboysfullbody",
     ]
 }
+
+// User themes live in $XDG_CONFIG_HOME/boykisserfetch/themes, or in
+// %APPDATA%\boykisserfetch\themes on Windows.
+pub fn get_themes_dir() -> Option<std::path::PathBuf> {
+    #[cfg(target_os = "windows")]
+    let config = std::env::var_os("APPDATA").map(std::path::PathBuf::from);
+
+    #[cfg(not(target_os = "windows"))]
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(std::path::PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| std::path::Path::new(&home).join(".config")));
+
+    Some(config?.join("boykisserfetch").join("themes"))
+}
@@ -1,61 +1,75 @@
-use super::colors::print;
-use crate::{
-    helpers::{self}, 
-    ActionType
+use super::{
+    art::{display_width, Art, Segment},
+    colors::{bold, print},
+    theme::Theme,
 };
 
-pub fn print_detail(title: &str, value: String, atype: ActionType, color: &str) {
-    print!("    ");
-    
+#[derive(Clone, Copy, Debug)]
+pub enum ActionType {
+    HostInfo,
+    Delimiter,
+    Details,
+    Colors
+}
+
+pub fn print_detail(title: &str, value: String, atype: ActionType, color: &str, theme: &Theme) {
+    let label = theme.label.clone().unwrap_or_else(|| bold(color));
+    let secondary = bold(&theme.secondary);
+
+    print!("{}", " ".repeat(theme.indent));
+
     match atype {
         ActionType::Details => {
-            print(&title, true, &(color.to_owned() + "_bold"));
-            for _ in 0..(12 - title.len()) {
-                print!(" ");
-            }
+            // Labels longer than the width just push their value further.
+            let title = theme.format_label(title);
+            let width = theme.label_width.unwrap_or(0);
+
+            print(&title, true, &label);
+            print!("{}", " ".repeat(width.saturating_sub(display_width(&title))));
 
-            helpers::colors::print(" : ", true, "white_bold");
+            print(&theme.separator, true, &secondary);
 
-            print!("{}", &value);
+            match &theme.value {
+                Some(color) => print(&value, true, color),
+                None => print!("{}", &value),
+            }
         },
         ActionType::Delimiter => {
-            print("-----------------------------", true, "white");
+            print(&theme.delimiter_line(), true, &theme.secondary);
         },
         ActionType::HostInfo => {
-            print(
-                title, 
-                true, 
-                &(color.to_owned() + "_bold")
-            );
-            print("@", true, "white_bold");
-            print(
-                &value, 
-                true, 
-                &(color.to_owned() + "_bold")
-            );
+            print(title, true, &label);
+            print("@", true, &secondary);
+            print(&value, true, &label);
         },
 
         ActionType::Colors => {
-            print("████", true, "black");
-            print("████", true, "red");
-            print("████", true, "green");
-            print("████", true, "yellow");
-            print("████", true, "blue");
-            print("████", true, "magenta");
+            for color in ["black", "red", "green", "yellow", "blue", "magenta"] {
+                print(&theme.blocks, true, color);
+            }
         }
     };
 }
 
-pub fn print_boykisserline(line: u16, boykisser: &str, color: &str) {
-    let mut lines = boykisser.split("\n");
-    let line = lines.nth(line as usize).unwrap().to_string();
-
-    print(
-        &format!("{:?}", line)
-            .replace("\"", "")
-            .replace("\\r", "")
-        , 
-        true, 
-        color
-    );
-}
\ No newline at end of file
+// Row `line` of `frame` as it is drawn, verbatim. Short lines and rows
+// below the art are padded to its width so the details column stays
+// aligned and frames fully cover each other.
+pub fn boykisser_line(line: u16, frame: usize, art: &Art) -> Vec<Segment> {
+    let mut segments = art.frames.get(frame)
+        .and_then(|frame| frame.lines.get(line as usize))
+        .cloned()
+        .unwrap_or_default();
+
+    let width = segments.iter().map(|segment| display_width(&segment.text)).sum::<usize>();
+    if width < art.width() {
+        segments.push(Segment { slot: 0, text: " ".repeat(art.width() - width) });
+    }
+
+    segments
+}
+
+pub fn print_boykisserline(line: u16, frame: usize, art: &Art, palette: &[String]) {
+    for segment in boykisser_line(line, frame, art) {
+        print(&segment.text, true, &palette[segment.slot]);
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use super::file;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_ppid(pid: u32) -> Option<u32> {
+    read_ppid(&pid.to_string())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn read_ppid(pid: &str) -> Option<u32> {
+    let stat = file::read(&format!("/proc/{}/stat", pid))?;
+
+    // The comm field is wrapped in parentheses and may itself contain
+    // spaces or parentheses, so everything is read after the last ')'.
+    let rest = &stat[stat.rfind(')')? + 1..];
+
+    rest.split_whitespace()
+        .nth(1)?
+        .parse::<u32>()
+        .ok()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_name(pid: u32) -> Option<String> {
+    let comm = file::read(&format!("/proc/{}/comm", pid))?;
+
+    Some(comm.trim().to_string())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_exe(pid: u32) -> Option<String> {
+    let exe = file::read_link(&format!("/proc/{}/exe", pid))?;
+
+    Some(exe.to_string_lossy().to_string())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_ancestors() -> Vec<u32> {
+    let mut pids = Vec::new();
+    let mut parent = read_ppid("self");
+
+    while let Some(ppid) = parent {
+        if ppid <= 1 {
+            break;
+        }
+
+        pids.push(ppid);
+        parent = get_ppid(ppid);
+    }
+
+    pids
+}
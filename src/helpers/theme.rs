@@ -0,0 +1,217 @@
+use super::{art::display_width, colors::COLORS, paths::get_themes_dir};
+
+// Built-in themes, a user theme with the same name replaces one.
+const BUILTIN: &[(&str, &str)] = &[
+    ("default", include_str!("../../themes/default.theme")),
+    ("minimal", include_str!("../../themes/minimal.theme")),
+    ("mono", include_str!("../../themes/mono.theme")),
+    ("pastel", include_str!("../../themes/pastel.theme")),
+];
+
+// Columns the delimiter line is repeated to.
+pub const DELIMITER_WIDTH: usize = 29;
+
+pub const LABEL_CASES: [&str; 4] = ["none", "upper", "lower", "title"];
+
+// Theme files are `key = value` lines, values can be quoted to keep
+// surrounding spaces:
+//
+//     boykisser = yippie
+//     primary = bright_magenta
+//     separator = " │ "
+//
+// Anything left out keeps the default look.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    // Drawn unless -b is given.
+    pub boykisser: Option<String>,
+    // Titles and the art's first color slot unless -c is given.
+    pub primary: Option<String>,
+    // The "@" of the title, separators and delimiter lines.
+    pub secondary: String,
+    // Labels, the primary color in bold when unset.
+    pub label: Option<String>,
+    // Values, the terminal's color when unset.
+    pub value: Option<String>,
+    pub separator: String,
+    // Spaces in front of every line.
+    pub indent: usize,
+    // Columns labels are padded to, fitting the longest one when unset.
+    pub label_width: Option<usize>,
+    // One of LABEL_CASES.
+    pub label_case: String,
+    // Repeated to fill a delimiter line.
+    pub delimiter: String,
+    // Drawn once in every color of the color blocks.
+    pub blocks: String,
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme {
+            boykisser: None,
+            primary: None,
+            secondary: String::from("white"),
+            label: None,
+            value: None,
+            separator: String::from(" : "),
+            indent: 4,
+            label_width: None,
+            label_case: String::from("none"),
+            delimiter: String::from("-"),
+            blocks: String::from("████"),
+        }
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    match value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        true => &value[1..value.len() - 1],
+        false => value,
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+        None => String::new(),
+    }
+}
+
+fn read_theme(path: &std::path::Path) -> Result<Theme, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("Can't read {}: {}.", path.display(), err))?;
+
+    Theme::parse(&text).map_err(|err| format!("{}, {}.", path.display(), err))
+}
+
+impl Theme {
+    pub fn parse(text: &str) -> Result<Theme, String> {
+        let mut theme = Theme::default();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {}: expected <key> = <value>", i + 1));
+            };
+
+            theme.set(key.trim(), unquote(value.trim()))
+                .map_err(|err| format!("line {}: {}", i + 1, err))?;
+        }
+
+        Ok(theme)
+    }
+
+    // Sets one key as it's written in theme files.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let color = || match COLORS.iter().any(|(color, _)| *color == value) {
+            true => Ok(value.to_string()),
+            false => Err(format!("unknown color {}", value)),
+        };
+
+        match key {
+            "boykisser" => self.boykisser = Some(value.to_string()),
+            "primary" => self.primary = Some(color()?),
+            "secondary" => self.secondary = color()?,
+            "label" => self.label = Some(color()?),
+            "value" => self.value = Some(color()?),
+            "separator" => self.separator = value.to_string(),
+            "delimiter" => self.delimiter = value.to_string(),
+            "blocks" => self.blocks = value.to_string(),
+            "indent" => {
+                self.indent = value.parse().map_err(|_| format!("invalid indent {}", value))?;
+            },
+            "label_width" if value == "auto" => self.label_width = None,
+            "label_width" => {
+                let width = value.parse().map_err(|_| format!("invalid label width {}", value))?;
+                self.label_width = Some(width);
+            },
+            "label_case" => {
+                if !LABEL_CASES.contains(&value) {
+                    return Err(format!("unknown label case {}", value));
+                }
+
+                self.label_case = value.to_string();
+            },
+            key => return Err(format!("unknown key {}", key)),
+        }
+
+        Ok(())
+    }
+
+    // `label` in the theme's casing.
+    pub fn format_label(&self, label: &str) -> String {
+        match self.label_case.as_str() {
+            "upper" => label.to_uppercase(),
+            "lower" => label.to_lowercase(),
+            "title" => label.split(' ').map(capitalize).collect::<Vec<String>>().join(" "),
+            _ => label.to_string(),
+        }
+    }
+
+    // Loads a user or built-in theme by name, or a theme file by path.
+    pub fn load(name: &str) -> Result<Theme, String> {
+        Theme::load_in(name, get_themes_dir().as_deref())
+    }
+
+    // `load` with the user themes in `dir` instead of the config directory.
+    pub fn load_in(name: &str, dir: Option<&std::path::Path>) -> Result<Theme, String> {
+        if name.ends_with(".theme") || name.contains(['/', '\\']) {
+            return read_theme(std::path::Path::new(name));
+        }
+
+        let user = dir.map(|dir| dir.join(format!("{}.theme", name)));
+        if let Some(path) = user.filter(|path| path.is_file()) {
+            return read_theme(&path);
+        }
+
+        match BUILTIN.iter().find(|(builtin, _)| *builtin == name) {
+            Some((_, text)) => Theme::parse(text).map_err(|err| format!("{}, {}.", name, err)),
+            None => Err(format!("Unknown theme {}.", name)),
+        }
+    }
+
+    // Names of all themes, with whether they are user themes.
+    pub fn list() -> Vec<(String, bool)> {
+        Theme::list_in(get_themes_dir().as_deref())
+    }
+
+    // `list` with the user themes in `dir` instead of the config directory.
+    pub fn list_in(dir: Option<&std::path::Path>) -> Vec<(String, bool)> {
+        let mut themes = BUILTIN.iter()
+            .map(|(name, _)| (name.to_string(), false))
+            .collect::<Vec<(String, bool)>>();
+
+        let entries = dir
+            .and_then(|dir| std::fs::read_dir(dir).ok())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok());
+
+        for entry in entries {
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            if let Some(name) = name.strip_suffix(".theme") {
+                themes.retain(|(theme, _)| theme != name);
+                themes.push((name.to_string(), true));
+            }
+        }
+
+        themes.sort();
+        themes
+    }
+
+    pub fn delimiter_line(&self) -> String {
+        match display_width(&self.delimiter) {
+            0 => String::new(),
+            width => self.delimiter.repeat(DELIMITER_WIDTH / width),
+        }
+    }
+}
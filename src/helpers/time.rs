@@ -0,0 +1,32 @@
+#[cfg(unix)]
+pub fn format_timestamp(timestamp: i64, format: &str) -> Option<String> {
+    use std::ffi::{CStr, CString};
+
+    let format = CString::new(format).ok()?;
+    let mut buf = [0 as libc::c_char; 256];
+
+    // SAFETY: localtime_r only writes into the tm we hand it, and strftime
+    // never writes more than buf.len() bytes including the terminating null.
+    unsafe {
+        let mut tm: libc::tm = std::mem::zeroed();
+
+        if libc::localtime_r(&(timestamp as libc::time_t), &mut tm).is_null() {
+            return None;
+        }
+
+        let len = libc::strftime(buf.as_mut_ptr(), buf.len(), format.as_ptr(), &tm);
+
+        if len == 0 {
+            return None;
+        }
+
+        Some(CStr::from_ptr(buf.as_ptr()).to_string_lossy().to_string())
+    }
+}
+
+pub fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
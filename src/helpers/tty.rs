@@ -0,0 +1,15 @@
+// Height of the terminal stdout is attached to, or $LINES when it can't be
+// asked (not a tty, or not unix).
+pub fn rows() -> Option<u16> {
+    #[cfg(unix)]
+    // SAFETY: TIOCGWINSZ only writes into the winsize struct passed to it.
+    unsafe {
+        let mut size: libc::winsize = std::mem::zeroed();
+
+        if libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) == 0 && size.ws_row > 0 {
+            return Some(size.ws_row);
+        }
+    }
+
+    std::env::var("LINES").ok()?.parse().ok().filter(|rows| *rows > 0)
+}
@@ -0,0 +1,17 @@
+//! System information collectors behind the `boykisserfetch` CLI.
+//!
+//! ```no_run
+//! let info = boykisserfetch::collect();
+//! println!("{:?}", info);
+//! ```
+//!
+//! Collectors are grouped behind the `host`, `specs` and `net` features,
+//! all enabled by default. Individual values can be read through the
+//! module [`Registry`](modules::registry::Registry).
+#![allow(non_camel_case_types)]
+
+pub mod helpers;
+pub mod modules;
+pub mod system;
+
+pub use system::info::{collect, SystemInfo};
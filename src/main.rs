@@ -1,284 +1,376 @@
-#![allow(non_camel_case_types)]
-use helpers::arguments::Arguments;
-use helpers::boykissers::get_boykisser;
-
-mod helpers;
-mod system;
-
-#[derive(Clone, Copy, Debug)]
-pub enum ActionType {
-    HostInfo,
-    Delimiter,
-    Details,
-    Colors
+use std::io::Write;
+
+use boykisserfetch::{
+    helpers::{
+        self,
+        arguments::Arguments,
+        art::{self, Art},
+        boykissers::{self, boykisser, get_boykisser},
+        command::{CommandRunner, RecordingRunner},
+        print::ActionType,
+    },
+    modules::{registry::{Registry, LAYOUT_IDS}, Context, ModuleError},
+    system,
+};
+#[cfg(feature = "image")]
+use boykisserfetch::helpers::image::{Picture, Protocol};
+
+// "title", "separator" and "colors" are drawn by the layout itself, every
+// other id refers to a module in the registry.
+const DEFAULT_LAYOUT: &[&str] = &[
+    "title",
+    "separator",
+    "distro",
+    "product",
+    "kernel",
+    "host",
+    "bios",
+    "motherboard",
+    "virtualization",
+    "arch",
+    "shell",
+    "terminal",
+    "terminal-font",
+    "resolution",
+    "ip",
+    "cpu",
+    "cpu-temp",
+    "cpu-usage",
+    "load",
+    "disk",
+    "gpu",
+    "init",
+    "uptime",
+    "boot-time",
+    "locale",
+    "timezone",
+    "datetime",
+    "separator",
+    "colors",
+];
+
+fn get_layout(args: &Arguments, registry: &Registry) -> Vec<String> {
+    let is_layout_id = |id: &str| LAYOUT_IDS.contains(&id);
+
+    if !args.modules.is_empty() {
+        for id in args.modules.iter() {
+            if !is_layout_id(id) && !registry.contains(id) {
+                Arguments::print_err(&format!("Invalid module provided: {}.", id));
+            }
+        }
+
+        return args.modules.clone();
+    }
+
+    // Modules that aren't available on this platform are left out.
+    let mut layout = DEFAULT_LAYOUT.iter()
+        .filter(|id| is_layout_id(id) || registry.contains(id))
+        .map(|id| id.to_string())
+        .collect::<Vec<String>>();
+
+    // User-defined modules go right before the trailing delimiter and colors.
+    let position = layout.len() - 2;
+    layout.splice(position..position, args.custom.iter().map(|module| module.id.clone()));
+
+    layout
 }
 
-#[derive(Debug)]
-pub struct Action<'a> {
-    action_type: ActionType,
-    name: Option<&'a str>,
-    func: Option<fn() -> String>,
+// A layout entry as drawn by `print_detail`.
+#[derive(Clone)]
+struct Entry {
+    id: String,
+    title: String,
+    value: Result<String, ModuleError>,
+    action: ActionType,
 }
-fn get_uptime() -> String {
-    #[cfg(target_os = "linux")]
-    {
-        let uptime = std::fs::read_to_string("/proc/uptime")
-            .unwrap_or_else(|_| "0.0".to_string());
-        let uptime_seconds: f64 = uptime.split_whitespace().next().unwrap_or("0").parse().unwrap();
-        let hours = (uptime_seconds / 3600.0).floor();
-        let minutes = ((uptime_seconds % 3600.0) / 60.0).floor();
-        format!("{} hours, {} minutes", hours, minutes)
+
+fn collect_entry(id: &str, registry: &Registry, ctx: &Context) -> Entry {
+    let (title, value, action) = match id {
+        "title" => (system::host::get_user(), Ok(system::host::get_hostname()), ActionType::HostInfo),
+        "separator" => (String::new(), Ok(String::new()), ActionType::Delimiter),
+        "colors" => (String::new(), Ok(String::new()), ActionType::Colors),
+        id => (
+            registry.get(id).map_or(id, |module| module.label()).to_string(),
+            registry.collect(id, ctx).map(|value| value.to_string()),
+            ActionType::Details
+        ),
+    };
+
+    Entry { id: id.to_string(), title, value, action }
+}
+
+fn print_entry(entry: &Entry, args: &Arguments) {
+    let value = match &entry.value {
+        Ok(value) => value.clone(),
+        Err(err) => err.to_string(),
+    };
+
+    helpers::print::print_detail(
+        &entry.title,
+        value,
+        entry.action,
+        &args.color,
+        &args.theme
+    );
+}
+
+// Collects the volatile modules again every `interval` and redraws the rows
+// whose value changed, until Ctrl-C is caught.
+fn watch(
+    interval: std::time::Duration,
+    shown: &mut [(u16, Entry)],
+    registry: &Registry,
+    ctx: &Context,
+    args: &Arguments,
+    draw_art: &dyn Fn(u16)
+) {
+    let _ = std::io::stdout().flush();
+
+    while helpers::interrupt::wait(interval) {
+        // Rows below a terminal that got smaller since can't be addressed.
+        let visible = helpers::tty::rows().unwrap_or(u16::MAX);
+
+        for (row, entry) in shown.iter_mut() {
+            if *row >= visible || !registry.get(&entry.id).is_some_and(|module| module.is_volatile()) {
+                continue;
+            }
+
+            let fresh = collect_entry(&entry.id, registry, ctx);
+            if fresh.value == entry.value {
+                continue;
+            }
+
+            // The art is drawn again as well, it's what positions the value.
+            print!("\u{1b}[{};1H", *row + 1);
+            draw_art(*row);
+            print_entry(&fresh, args);
+            print!("\u{1b}[0m\u{1b}[K");
+
+            *entry = fresh;
+        }
+
+        let _ = std::io::stdout().flush();
+    }
+
+    print!("\u{1b}[0m\u{1b}[?25h\u{1b}[?1049l");
+    let _ = std::io::stdout().flush();
+}
+
+fn transform_art(args: &Arguments, art: &Art) -> Art {
+    let mut art = art.scale(args.scale);
+
+    if args.mirror {
+        art = art.mirror();
     }
 
-    #[cfg(target_os = "windows")]
-    {
-        use std::process::Command;
-        let output = Command::new("wmic")
-            .arg("os")
-            .arg("get")
-            .arg("LastBootUpTime")
-            .output()
-            .expect("Failed to execute command");
-        
-        let boot_time_str = String::from_utf8_lossy(&output.stdout);
-        let boot_time = boot_time_str.lines().nth(1).unwrap_or("0").trim();
-        
-        // Parse the boot time and calculate uptime
-        let boot_time = chrono::DateTime::parse_from_str(boot_time, "%Y%m%d%H%M%S.%f%z").unwrap();
-        let uptime = chrono::Utc::now().signed_duration_since(boot_time);
-        format!("{} hours, {} minutes", uptime.num_hours(), uptime.num_minutes() % 60)
+    if args.flip {
+        art = art.flip();
     }
 
-    #[cfg(target_os = "macos")]
-    {
-        let output = Command::new("sysctl")
-            .arg("kern.boottime")
-            .output()
-            .expect("Failed to execute command");
-        
-        let boot_time_str = String::from_utf8_lossy(&output.stdout);
-        let boot_time = boot_time_str.split_whitespace().nth(3).unwrap_or("0").trim_matches(',');
-        
-        // Parse the boot time and calculate uptime
-        let boot_time = chrono::DateTime::from_utc(
-            chrono::NaiveDateTime::from_timestamp(boot_time.parse::<i64>().unwrap(), 0),
-            chrono::Utc,
-        );
-        let uptime = chrono::Utc::now().signed_duration_since(boot_time);
-        format!("{} hours, {} minutes", uptime.num_hours(), uptime.num_minutes() % 60)
+    match args.max_height {
+        Some(height) => art.crop(height),
+        None => art,
     }
 }
 
-fn get_gpus() -> String {
-    let mut gpus = Vec::new();
-
-    #[cfg(target_os = "linux")]
-    {
-        // Use lspci to list all GPUs
-        let output = std::process::Command::new("lspci")
-            .arg("-nn")
-            .output()
-            .expect("Failed to execute command");
-        
-        let output_str = String::from_utf8_lossy(&output.stdout);
-        for line in output_str.lines() {
-            if line.contains("VGA compatible controller") || line.contains("3D controller") {
-                gpus.push(format!("GPU          : {}", line.trim()));
-            }
+// Prints the size of every built-in art and what's wrong with it, exiting
+// with 1 if anything is.
+fn check_art() {
+    let mut failed = false;
+
+    for name in helpers::paths::get_boykissers() {
+        let source = boykissers::get_source(name).unwrap_or_default();
+        let art = Art::parse(source);
+        let problems = art::check(source);
+
+        let frames = match art.frames.len() {
+            1 => String::new(),
+            frames => format!(", {} frames", frames),
+        };
+
+        println!("{:<24}{}x{}{}", name, art.width(), art.height(), frames);
+        for problem in problems.iter() {
+            println!("    {}", problem);
         }
+
+        failed |= !problems.is_empty();
     }
 
-    #[cfg(target_os = "windows")]
-    {
-        // Use wmic to list all GPUs
-        let output = std::process::Command::new("wmic")
-            .arg("path")
-            .arg("win32_VideoController")
-            .arg("get")
-            .arg("name")
-            .output()
-            .expect("Failed to execute command");
-        
-        let output_str = String::from_utf8_lossy(&output.stdout);
-        for line in output_str.lines().skip(1) { // Skip the header
-            let gpu = line.trim();
-            if !gpu.is_empty() {
-                gpus.push(format!("GPU          : GPU: {}", gpu));
-            }
-        }
+    std::process::exit(failed as i32);
+}
+
+fn print_modules(registry: &Registry) {
+    println!("Available modules:");
+    for module in registry.iter() {
+        println!("    {:<16}{}", module.id(), module.label());
     }
 
-    #[cfg(target_os = "macos")]
-    {
-        // Use system_profiler to list all GPUs
-        let output = std::process::Command::new("system_profiler")
-            .arg("SPDisplaysDataType")
-            .output()
-            .expect("Failed to execute command");
-        
-        let output_str = String::from_utf8_lossy(&output.stdout);
-        for line in output_str.lines() {
-            if line.contains("Chipset Model:") {
-                let gpu = line.replace("Chipset Model:", "").trim();
-                gpus.push(format!("GPU          : GPU: {}", gpu));
-            }
+    std::process::exit(0);
+}
+
+#[cfg(feature = "image")]
+fn load_picture(args: &Arguments, rows: u16) -> Option<Picture> {
+    let path = args.image.as_ref()?;
+
+    let protocol = match Protocol::from_name(&args.image_protocol) {
+        Some(protocol) => protocol,
+        None => {
+            Arguments::print_err("Invalid image protocol provided.");
+            std::process::exit(1);
         }
-    }
+    };
 
-    // Join all GPU names with a newline character
-    gpus.join("\n")
+    match Picture::load(path, rows, protocol) {
+        Ok(picture) => Some(picture),
+        Err(err) => {
+            Arguments::print_err(&err);
+            std::process::exit(1);
+        }
+    }
 }
 
+// Returns the height of the art, how many rows the info block starts below
+// its top and how to draw one of its rows. An image is scaled to the height
+// of the info block, so it needs no offset.
+fn art_column<'a>(
+    args: &Arguments,
+    info_rows: u16,
+    boykisser: &'a boykisser,
+    palette: &'a [String]
+) -> (u16, f32, Box<dyn Fn(u16) + 'a>) {
+    #[cfg(feature = "image")]
+    if let Some(picture) = load_picture(args, info_rows) {
+        // Drawn along with the first row, so it lands in --watch's screen.
+        return (picture.rows, 0.0, Box::new(move |i| {
+            if i == 0 {
+                picture.draw();
+            }
 
-const ACTIONS: [Action; 14] = [
-    Action {
-        action_type: ActionType::HostInfo,
-        name: None,
-        func: Some(system::host::get_hostname),
-    },
-    Action {
-        action_type: ActionType::Delimiter,
-        name: None,
-        func: None,
-    },
-    #[cfg(target_os = "linux")]
-    Action {
-        action_type: ActionType::Details,
-        name: Some("Distro"),
-        func: Some(system::host::get_distro),
-    },
-    #[cfg(target_os = "windows")]
-    Action {
-        action_type: ActionType::Details,
-        name: Some("Product"),
-        func: Some(system::specs::get_kernel),
-    },
-    #[cfg(target_os = "linux")]
-    Action {
-        action_type: ActionType::Details,
-        name: Some("Kernel"),
-        func: Some(system::host::get_kernel),
-    },
-    Action {
-        action_type: ActionType::Details,
-        name: Some("Arch"),
-        func: Some(system::specs::get_arch),
-    },
-    Action {
-        action_type: ActionType::Details,
-        name: Some("Shell"),
-        func: Some(system::host::get_shell),
-    },
-    Action {
-        action_type: ActionType::Details,
-        name: Some("Resolution"),
-        func: Some(system::host::get_resolution),
-    },
-    Action {
-        action_type: ActionType::Details,
-        name: Some("IP"),
-        func: Some(system::net::get_ipaddr),
-    },
-    Action {
-        action_type: ActionType::Details,
-        name: Some("CPU"),
-        func: Some(system::specs::get_cpu),
-    },
-    #[cfg(target_os = "windows")]
-    Action {
-        action_type: ActionType::Details,
-        name: Some("Disk usage"),
-        func: Some(system::specs::get_disk_usage),
-    },
+            picture.print_line(i)
+        }));
+    }
 
-    #[cfg(target_os = "windows")]
-    Action {
-        action_type: ActionType::Details,
-        name: Some("GPU"),
-        func: Some(system::specs::get_gpu),
-    },
-    Action {
-        action_type: ActionType::Details,
-        name: Some("GPU"),
-        func: Some(get_gpus), // Use the updated function defined above
-    },
-    #[cfg(target_os = "linux")]
-    Action {
-        action_type: ActionType::Details,
-        name: Some("Init System"),
-        func: Some(system::host::get_init_system),
-    },
-    Action {
-    action_type: ActionType::Details,
-    name: Some("Uptime"),
-    func: Some(get_uptime),
-    },
-    Action {
-        action_type: ActionType::Delimiter,
-        name: None,
-        func: None,
-    },
-    Action {
-        action_type: ActionType::Colors,
-        name: None,
-        func: None,
+    #[cfg(not(feature = "image"))]
+    if args.image.is_some() {
+        let _ = info_rows;
+        Arguments::print_err("boykisserfetch was built without image support.");
     }
-];
+
+    // Small art (--scale, --max-height) starts next to the first row.
+    let to_skip = (((boykisser.lines / 2) as f32).floor() - 6.0).max(0.0);
+
+    (boykisser.lines, to_skip, Box::new(move |i| {
+        helpers::print::print_boykisserline(i, 0, &boykisser.art, palette)
+    }))
+}
 
 fn main() {
     let args = Arguments::parse();
-    let boykisser = get_boykisser(args.boykisser).unwrap();
 
-    let to_skip = ((boykisser.lines / 2) as f32).floor() - 6.0;
+    match args.record_commands.clone() {
+        Some(dir) => RecordingRunner::new(dir).enter(|| fetch(args)),
+        None => fetch(args),
+    }
+}
 
-    for i in 0..boykisser.lines {
-        helpers::print::print_boykisserline(i, &boykisser.text, &args.color);
+fn fetch(mut args: Arguments) {
+    args.resolve_color();
 
-        let pad_i = (i as f32 - to_skip).floor();
+    if args.check_art {
+        check_art();
+    }
+
+    let mut boykisser = get_boykisser(args.boykisser.clone()).unwrap();
+    boykisser.art = transform_art(&args, &boykisser.art);
+    boykisser.lines = boykisser.art.height() as u16;
+
+    let mut registry = Registry::builtin();
+    for module in args.custom.iter_mut() {
+        *module = module.clone().with_unique_id(|id| registry.is_taken(id));
+        registry.register(Box::new(module.clone()));
+    }
 
-        if ACTIONS.get(pad_i as usize).is_none() || pad_i < 0.0 {
+    if args.list_modules {
+        print_modules(&registry);
+    }
+
+    let ctx = Context {
+        uptime_style: args.uptime.clone(),
+        date_format: args.date_format.clone(),
+        command_timeout: std::time::Duration::from_millis(args.command_timeout),
+        cpu_temperature: args.cpu_temp,
+        cpu_sparkline: args.cpu_sparkline,
+        init_services: args.init_services,
+    };
+
+    let layout = get_layout(&args, &registry);
+
+    if args.watch.is_some() {
+        // Ctrl-C has to restore the terminal from here on, even while the
+        // first frame is still being collected.
+        helpers::interrupt::catch();
+    }
+
+    // Modules with nothing to show are left out, unless they were picked
+    // with --modules.
+    let entries = layout.iter()
+        .map(|id| collect_entry(id, &registry, &ctx))
+        .filter(|entry| !args.modules.is_empty() || entry.value != Err(ModuleError::Unavailable))
+        .collect::<Vec<Entry>>();
+
+    if args.watch.is_some() && helpers::interrupt::interrupted() {
+        return;
+    }
+
+    // Labels are padded to the longest one shown unless a width is set.
+    if args.theme.label_width.is_none() {
+        let width = entries.iter()
+            .filter(|entry| matches!(entry.action, ActionType::Details))
+            .map(|entry| art::display_width(&args.theme.format_label(&entry.title)))
+            .max();
+
+        args.theme.label_width = width;
+    }
+    let palette = boykisser.art.palette(&args.color, args.color_given);
+
+    let (art_rows, to_skip, draw_art) = art_column(&args, entries.len() as u16, &boykisser, &palette);
+    let mut rows = art_rows.max((to_skip as i32 + entries.len() as i32) as u16);
+
+    if args.watch.is_some() {
+        // Alternate screen with a hidden cursor, rows are addressed from the top.
+        // Anything taller than the terminal would scroll it, so it's cut off.
+        print!("\u{1b}[?1049h\u{1b}[H\u{1b}[?25l");
+        rows = rows.min(helpers::tty::rows().unwrap_or(u16::MAX));
+    }
+
+    let mut shown = Vec::new();
+
+    for i in 0..rows {
+        if i > 0 {
             println!();
-            continue;
         }
 
-        match ACTIONS[pad_i as usize].action_type {
-            ActionType::HostInfo => {
-                helpers::print::print_detail(
-                    &system::host::get_user(),
-                    system::host::get_hostname(),
-                    ActionType::HostInfo,
-                    &args.color
-                );
-            },
-                    
-            ActionType::Delimiter => {
-                helpers::print::print_detail(
-                    "",
-                    "".to_string(),
-                    ActionType::Delimiter,
-                    args.color.as_str()
-                );
-            },
-              
-            ActionType::Details => {
-                helpers::print::print_detail(
-                    ACTIONS[pad_i as usize].name.unwrap(),
-                    ACTIONS[pad_i as usize].func.unwrap()(),
-                    ACTIONS[pad_i as usize].action_type,
-                    args.color.as_str()
-                );
-            },
-
-            ActionType::Colors => {
-                helpers::print::print_detail(
-                    "",
-                    "".to_string(),
-                    ActionType::Colors,
-                    args.color.as_str()
-                );
-            }
+        draw_art(i);
+
+        let pad_i = (i as f32 - to_skip).floor();
+
+        if entries.get(pad_i as usize).is_none() || pad_i < 0.0 {
+            continue;
         }
 
+        let entry = &entries[pad_i as usize];
+        print_entry(entry, &args);
+        shown.push((i, entry.clone()));
+    }
+
+    if args.watch.is_none() {
         println!();
     }
+
+    if let Some(secs) = args.watch {
+        let interval = std::time::Duration::from_secs_f64(secs);
+        watch(interval, &mut shown, &registry, &ctx, &args, &draw_art);
+    } else if args.animate && args.image.is_none() {
+        helpers::animation::play(&boykisser.art, &palette, rows);
+    }
 }
@@ -0,0 +1,289 @@
+use super::{registry::Registry, Context, Module, ModuleError, ModuleValue, Platform, Result};
+use crate::system::{self, custom::CustomModule};
+
+#[allow(dead_code)]
+const LINUX: &[Platform] = &[Platform::Linux];
+#[allow(dead_code)]
+const WINDOWS: &[Platform] = &[Platform::Windows];
+#[allow(dead_code)]
+const UNIX: &[Platform] = &[Platform::Linux, Platform::MacOs];
+
+enum Collector {
+    Text(fn() -> String),
+    List(fn() -> Vec<String>),
+    WithContext(fn(&Context) -> String),
+}
+
+// Adapts the plain `system::*` collector functions to the `Module` trait.
+pub struct FnModule {
+    id: &'static str,
+    label: &'static str,
+    platforms: &'static [Platform],
+    collector: Collector,
+    volatile: bool,
+}
+
+impl FnModule {
+    pub fn new(
+        id: &'static str,
+        label: &'static str,
+        platforms: &'static [Platform],
+        func: fn() -> String
+    ) -> FnModule {
+        FnModule { id, label, platforms, collector: Collector::Text(func), volatile: false }
+    }
+
+    pub fn list(
+        id: &'static str,
+        label: &'static str,
+        platforms: &'static [Platform],
+        func: fn() -> Vec<String>
+    ) -> FnModule {
+        FnModule { id, label, platforms, collector: Collector::List(func), volatile: false }
+    }
+
+    pub fn with_context(
+        id: &'static str,
+        label: &'static str,
+        platforms: &'static [Platform],
+        func: fn(&Context) -> String
+    ) -> FnModule {
+        FnModule { id, label, platforms, collector: Collector::WithContext(func), volatile: false }
+    }
+
+    pub fn volatile(self) -> FnModule {
+        FnModule { volatile: true, ..self }
+    }
+}
+
+fn text_value(text: String) -> Result<ModuleValue> {
+    let text = text.trim();
+
+    if text.is_empty() || text == "Unknown" {
+        return Err(ModuleError::Unavailable);
+    }
+
+    Ok(ModuleValue::Text(text.to_string()))
+}
+
+impl Module for FnModule {
+    fn id(&self) -> &str {
+        self.id
+    }
+
+    fn label(&self) -> &str {
+        self.label
+    }
+
+    fn platforms(&self) -> &[Platform] {
+        self.platforms
+    }
+
+    fn is_volatile(&self) -> bool {
+        self.volatile
+    }
+
+    fn collect(&self, ctx: &Context) -> Result<ModuleValue> {
+        match &self.collector {
+            Collector::Text(func) => text_value(func()),
+            Collector::WithContext(func) => text_value(func(ctx)),
+            Collector::List(func) => match func() {
+                items if items.is_empty() => Err(ModuleError::Unavailable),
+                items => Ok(ModuleValue::List(items)),
+            },
+        }
+    }
+}
+
+impl Module for CustomModule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn collect(&self, ctx: &Context) -> Result<ModuleValue> {
+        text_value(system::custom::collect(self, ctx.command_timeout))
+    }
+
+    // Commands and files are user-defined, so they might change.
+    fn is_volatile(&self) -> bool {
+        true
+    }
+}
+
+// Keeps the last sample of a module that measures a rate. Only the first
+// collection waits for a second sample, later ones (--watch) measure since
+// the previous collection.
+#[cfg(all(target_os = "linux", any(feature = "specs", feature = "net")))]
+pub struct Sampler<T> {
+    previous: std::sync::Mutex<Option<(std::time::Instant, T)>>,
+}
+
+#[cfg(all(target_os = "linux", any(feature = "specs", feature = "net")))]
+impl<T> Default for Sampler<T> {
+    fn default() -> Sampler<T> {
+        Sampler { previous: std::sync::Mutex::new(None) }
+    }
+}
+
+#[cfg(all(target_os = "linux", any(feature = "specs", feature = "net")))]
+impl<T: Clone> Sampler<T> {
+    // Returns the previous and the current sample and the time between them,
+    // which is at least `wait`.
+    pub fn measure(&self, wait: std::time::Duration, sample: fn() -> T) -> (T, T, std::time::Duration) {
+        let mut previous = self.previous.lock().unwrap_or_else(|err| err.into_inner());
+        let (taken, before) = previous.take()
+            .unwrap_or_else(|| (std::time::Instant::now(), sample()));
+
+        std::thread::sleep(wait.saturating_sub(taken.elapsed()));
+
+        let after = sample();
+        let elapsed = taken.elapsed();
+        *previous = Some((std::time::Instant::now(), after.clone()));
+
+        (before, after, elapsed)
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "specs"))]
+#[derive(Default)]
+pub struct CpuUsageModule {
+    sampler: Sampler<system::specs::CpuTimes>,
+}
+
+#[cfg(all(target_os = "linux", feature = "specs"))]
+impl Module for CpuUsageModule {
+    fn id(&self) -> &str {
+        "cpu-usage"
+    }
+
+    fn label(&self) -> &str {
+        "CPU Usage"
+    }
+
+    fn platforms(&self) -> &[Platform] {
+        LINUX
+    }
+
+    fn is_volatile(&self) -> bool {
+        true
+    }
+
+    fn collect(&self, ctx: &Context) -> Result<ModuleValue> {
+        let (before, after, _) = self.sampler
+            .measure(system::specs::CPU_SAMPLE_TIME, system::specs::sample_cpu_times);
+
+        text_value(system::specs::format_cpu_usage(&before, &after, ctx.cpu_sparkline))
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "net"))]
+#[derive(Default)]
+pub struct NetworkRateModule {
+    sampler: Sampler<system::net::NetBytes>,
+}
+
+#[cfg(all(target_os = "linux", feature = "net"))]
+impl Module for NetworkRateModule {
+    fn id(&self) -> &str {
+        "network-rate"
+    }
+
+    fn label(&self) -> &str {
+        "Network"
+    }
+
+    fn platforms(&self) -> &[Platform] {
+        LINUX
+    }
+
+    fn is_volatile(&self) -> bool {
+        true
+    }
+
+    fn collect(&self, _ctx: &Context) -> Result<ModuleValue> {
+        let (before, after, elapsed) = self.sampler
+            .measure(system::net::NET_SAMPLE_TIME, system::net::sample_net_bytes);
+
+        text_value(system::net::format_net_rate(&before, &after, elapsed))
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    let modules: Vec<FnModule> = vec![
+        #[cfg(all(target_os = "linux", feature = "host"))]
+        FnModule::new("distro", "Distro", LINUX, system::distro::get_distro),
+        #[cfg(all(target_os = "windows", feature = "specs"))]
+        FnModule::new("product", "Product", WINDOWS, system::specs::get_kernel),
+        #[cfg(all(target_os = "linux", feature = "specs"))]
+        FnModule::new("host", "Host", LINUX, system::specs::get_host_model),
+        #[cfg(all(target_os = "linux", feature = "specs"))]
+        FnModule::new("bios", "BIOS", LINUX, system::specs::get_bios),
+        #[cfg(all(target_os = "linux", feature = "specs"))]
+        FnModule::new("motherboard", "Motherboard", LINUX, system::specs::get_motherboard),
+        #[cfg(all(target_os = "linux", feature = "specs"))]
+        FnModule::new("virtualization", "Virtualization", LINUX, system::virt::get_virtualization),
+        #[cfg(all(target_os = "linux", feature = "host"))]
+        FnModule::new("kernel", "Kernel", LINUX, system::host::get_kernel),
+        #[cfg(feature = "specs")]
+        FnModule::new("arch", "Arch", Platform::ALL, system::specs::get_arch),
+        #[cfg(feature = "host")]
+        FnModule::new("shell", "Shell", Platform::ALL, system::host::get_shell),
+        #[cfg(feature = "host")]
+        FnModule::new("terminal", "Terminal", Platform::ALL, system::terminal::get_terminal),
+        #[cfg(all(target_os = "linux", feature = "host"))]
+        FnModule::new("terminal-font", "Terminal Font", LINUX, system::terminal::get_terminal_font),
+        #[cfg(feature = "host")]
+        FnModule::new("resolution", "Resolution", Platform::ALL, system::host::get_resolution),
+        #[cfg(feature = "net")]
+        FnModule::new("ip", "IP", Platform::ALL, system::net::get_ipaddr).volatile(),
+        #[cfg(feature = "specs")]
+        FnModule::with_context("cpu", "CPU", Platform::ALL, |ctx| match ctx.cpu_temperature {
+            #[cfg(target_os = "linux")]
+            true => system::specs::get_cpu_with_temperature(),
+            _ => system::specs::get_cpu(),
+        }),
+        #[cfg(all(target_os = "linux", feature = "specs"))]
+        FnModule::new("cpu-temp", "CPU Temp", LINUX, system::specs::get_cpu_temperature).volatile(),
+        #[cfg(all(target_os = "linux", feature = "specs"))]
+        FnModule::new("load", "Load", LINUX, system::specs::get_load).volatile(),
+        #[cfg(all(target_os = "linux", feature = "specs"))]
+        FnModule::new("battery", "Battery", LINUX, system::specs::get_battery).volatile(),
+        #[cfg(all(target_os = "windows", feature = "specs"))]
+        FnModule::new("disk", "Disk usage", WINDOWS, system::specs::get_disk_usage),
+        #[cfg(feature = "specs")]
+        FnModule::list("gpu", "GPU", Platform::ALL, system::specs::get_gpus),
+        #[cfg(feature = "specs")]
+        FnModule::new("memory", "Memory", Platform::ALL, system::specs::get_ram_used).volatile(),
+        #[cfg(all(target_os = "linux", feature = "host"))]
+        FnModule::with_context("init", "Init System", LINUX, |ctx| {
+            system::host::get_init_system(ctx.init_services)
+        }),
+        #[cfg(feature = "host")]
+        FnModule::with_context("uptime", "Uptime", Platform::ALL, |ctx| {
+            system::host::get_uptime(&ctx.uptime_style)
+        }).volatile(),
+        #[cfg(all(target_os = "linux", feature = "host"))]
+        FnModule::new("boot-time", "Boot Time", LINUX, system::host::get_boot_time),
+        #[cfg(feature = "host")]
+        FnModule::new("locale", "Locale", Platform::ALL, system::locale::get_locale),
+        #[cfg(all(unix, feature = "host"))]
+        FnModule::new("timezone", "Timezone", UNIX, system::locale::get_timezone),
+        #[cfg(all(unix, feature = "host"))]
+        FnModule::with_context("datetime", "Date/Time", UNIX, |ctx| {
+            system::locale::get_datetime(&ctx.date_format)
+        }).volatile(),
+    ];
+
+    for module in modules {
+        registry.register(Box::new(module));
+    }
+
+    #[cfg(all(target_os = "linux", feature = "specs"))]
+    registry.register(Box::new(CpuUsageModule::default()));
+    #[cfg(all(target_os = "linux", feature = "net"))]
+    registry.register(Box::new(NetworkRateModule::default()));
+}
@@ -0,0 +1,110 @@
+pub mod builtin;
+pub mod registry;
+
+use std::{fmt, time::Duration};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Platform {
+    Linux,
+    Windows,
+    MacOs,
+}
+
+impl Platform {
+    pub const ALL: &'static [Platform] = &[Platform::Linux, Platform::Windows, Platform::MacOs];
+
+    pub fn current() -> Platform {
+        #[cfg(target_os = "windows")]
+        return Platform::Windows;
+
+        #[cfg(target_os = "macos")]
+        return Platform::MacOs;
+
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        return Platform::Linux;
+    }
+}
+
+// Options collectors may need, filled in from the command line.
+#[derive(Clone, Debug)]
+pub struct Context {
+    pub uptime_style: String,
+    pub date_format: String,
+    pub command_timeout: Duration,
+    // Whether the CPU line ends with its temperature.
+    pub cpu_temperature: bool,
+    // Whether CPU usage is followed by a sparkline of every core.
+    pub cpu_sparkline: bool,
+    // Whether the init system line counts its services.
+    pub init_services: bool,
+}
+
+impl Default for Context {
+    fn default() -> Context {
+        Context {
+            uptime_style: String::from("long"),
+            date_format: String::from("%Y-%m-%d %H:%M:%S"),
+            command_timeout: Duration::from_millis(1000),
+            cpu_temperature: false,
+            cpu_sparkline: false,
+            init_services: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ModuleValue {
+    Text(String),
+    List(Vec<String>),
+}
+
+impl fmt::Display for ModuleValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ModuleValue::Text(text) => write!(f, "{}", text),
+            ModuleValue::List(items) => write!(f, "{}", items.join(", ")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ModuleError {
+    Unsupported,
+    Unavailable,
+}
+
+impl fmt::Display for ModuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ModuleError::Unsupported => write!(f, "Unsupported"),
+            ModuleError::Unavailable => write!(f, "Unknown"),
+        }
+    }
+}
+
+impl std::error::Error for ModuleError {}
+
+pub type Result<T> = std::result::Result<T, ModuleError>;
+
+pub trait Module {
+    // Stable identifier used to enable the module, e.g. "cpu".
+    fn id(&self) -> &str;
+
+    // Label shown in front of the value, e.g. "CPU".
+    fn label(&self) -> &str;
+
+    fn platforms(&self) -> &[Platform] {
+        Platform::ALL
+    }
+
+    fn collect(&self, ctx: &Context) -> Result<ModuleValue>;
+
+    // Whether the value changes while we run, --watch collects these again.
+    fn is_volatile(&self) -> bool {
+        false
+    }
+
+    fn is_supported(&self) -> bool {
+        self.platforms().contains(&Platform::current())
+    }
+}
@@ -0,0 +1,59 @@
+use super::{Module, ModuleError, Context, Result, ModuleValue};
+
+// Ids the layout draws by itself instead of asking a module.
+pub const LAYOUT_IDS: &[&str] = &["title", "separator", "colors"];
+
+#[derive(Default)]
+pub struct Registry {
+    modules: Vec<Box<dyn Module>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    // A registry with every collector available on this platform.
+    pub fn builtin() -> Registry {
+        let mut registry = Registry::new();
+        super::builtin::register(&mut registry);
+
+        registry
+    }
+
+    // Registering an id twice replaces the earlier module.
+    pub fn register(&mut self, module: Box<dyn Module>) {
+        self.modules.retain(|existing| existing.id() != module.id());
+        self.modules.push(module);
+    }
+
+    pub fn get(&self, id: &str) -> Option<&dyn Module> {
+        self.modules.iter()
+            .find(|module| module.id() == id)
+            .map(|module| module.as_ref())
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.get(id).is_some()
+    }
+
+    // Whether a new module with this id would replace or be hidden by
+    // something else in the layout.
+    pub fn is_taken(&self, id: &str) -> bool {
+        LAYOUT_IDS.contains(&id) || self.contains(id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Module> {
+        self.modules.iter().map(|module| module.as_ref())
+    }
+
+    pub fn collect(&self, id: &str, ctx: &Context) -> Result<ModuleValue> {
+        let module = self.get(id).ok_or(ModuleError::Unavailable)?;
+
+        if !module.is_supported() {
+            return Err(ModuleError::Unsupported);
+        }
+
+        module.collect(ctx)
+    }
+}
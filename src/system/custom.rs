@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use crate::helpers::command::{self, RunError};
+
+#[derive(Clone, Debug)]
+pub enum CustomSource {
+    Command(String),
+    File(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct CustomModule {
+    pub id: String,
+    pub label: String,
+    pub source: CustomSource,
+}
+
+impl CustomModule {
+    // Parses the "<label>:<command or path>" value of --command / --file.
+    pub fn parse(value: &str, file: bool) -> Option<CustomModule> {
+        let (label, source) = value.split_once(':')?;
+
+        if label.trim().is_empty() || source.trim().is_empty() {
+            return None;
+        }
+
+        let source = match file {
+            true => CustomSource::File(source.trim().to_string()),
+            false => CustomSource::Command(source.trim().to_string()),
+        };
+
+        Some(CustomModule {
+            id: label.trim().to_lowercase().split_whitespace().collect::<Vec<&str>>().join("-"),
+            label: label.trim().to_string(),
+            source,
+        })
+    }
+
+    // Numbers the id ("cpu-2") while `taken` says it's in use, so a module
+    // labeled like a built-in one doesn't replace it.
+    pub fn with_unique_id<F: Fn(&str) -> bool>(self, taken: F) -> CustomModule {
+        let mut id = self.id.clone();
+
+        for n in 2.. {
+            if !taken(&id) {
+                break;
+            }
+
+            id = format!("{}-{}", self.id, n);
+        }
+
+        CustomModule { id, ..self }
+    }
+}
+
+// User commands run through the shell, so pipes and quoting work.
+#[cfg(target_os = "windows")]
+const SHELL: [&str; 2] = ["cmd", "/C"];
+#[cfg(not(target_os = "windows"))]
+const SHELL: [&str; 2] = ["sh", "-c"];
+
+pub fn collect(module: &CustomModule, timeout: Duration) -> String {
+    let output = match &module.source {
+        CustomSource::Command(command) => {
+            match command::run_timeout(SHELL[0], &[SHELL[1], command], timeout) {
+                Ok(output) => Some(output),
+                Err(RunError::TimedOut) => Some("Timed out".to_string()),
+                Err(RunError::Failed) => None,
+            }
+        },
+        CustomSource::File(path) => std::fs::read_to_string(path).ok(),
+    };
+
+    match output {
+        Some(output) => output.split_whitespace().collect::<Vec<&str>>().join(" "),
+        None => "Unknown".to_string(),
+    }
+}
@@ -0,0 +1,194 @@
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use {
+    crate::helpers::{command, file},
+    std::collections::HashMap,
+};
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[derive(Debug, Default)]
+pub struct OsRelease {
+    pub name: String,
+    pub pretty_name: Option<String>,
+    pub id: Option<String>,
+    pub id_like: Vec<String>,
+    pub version: Option<String>,
+    pub version_id: Option<String>,
+    pub build_id: Option<String>,
+    pub ansi_color: Option<String>,
+}
+
+// Parses a shell-like assignment value as described in os-release(5):
+// unquoted, 'single quoted' or "double quoted" with backslash escapes.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parse_value(value: &str) -> String {
+    let value = value.trim();
+    let mut final_str = String::new();
+    let mut chars = value.chars();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (Some('\''), c) => final_str.push(c),
+            (_, '\\') => {
+                if let Some(escaped) = chars.next() {
+                    final_str.push(escaped);
+                }
+            },
+            (_, c) => final_str.push(c),
+        }
+    }
+
+    final_str.trim_end().to_string()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn parse_assignments(content: &str) -> HashMap<String, String> {
+    content.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), parse_value(value)))
+        .collect()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn from_os_release(content: &str) -> OsRelease {
+    let mut fields = parse_assignments(content);
+    let mut take = |key: &str| fields.remove(key).filter(|value| !value.is_empty());
+
+    OsRelease {
+        name: take("NAME").unwrap_or_else(|| "Linux".to_string()),
+        pretty_name: take("PRETTY_NAME"),
+        id: take("ID"),
+        id_like: take("ID_LIKE")
+            .map(|ids| ids.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default(),
+        version: take("VERSION"),
+        version_id: take("VERSION_ID"),
+        build_id: take("BUILD_ID"),
+        ansi_color: take("ANSI_COLOR"),
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn from_lsb_release(content: &str) -> Option<OsRelease> {
+    let fields = parse_assignments(content);
+    let name = fields.get("DISTRIB_ID")?.to_string();
+
+    Some(OsRelease {
+        pretty_name: fields.get("DISTRIB_DESCRIPTION").cloned(),
+        id: Some(name.to_lowercase()),
+        version_id: fields.get("DISTRIB_RELEASE").cloned(),
+        name,
+        ..Default::default()
+    })
+}
+
+// Legacy single line files such as /etc/redhat-release or /etc/gentoo-release.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn from_release_file() -> Option<OsRelease> {
+    let files = file::read_dir("/etc")
+        .into_iter()
+        .filter(|path| {
+            let name = path.trim_start_matches("/etc/");
+            name.ends_with("-release") && name != "os-release" && name != "lsb-release"
+        })
+        .collect::<Vec<String>>();
+
+    files.iter().find_map(|path| {
+        let content = file::read(path)?;
+        let line = content.lines().next()?.trim().to_string();
+
+        if line.is_empty() {
+            return None;
+        }
+
+        Some(OsRelease {
+            name: line.clone(),
+            pretty_name: Some(line),
+            ..Default::default()
+        })
+    })
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn from_getprop() -> Option<OsRelease> {
+    let output = command::run("getprop", &["ro.build.version.release"])?;
+    let version = output.trim().to_string();
+
+    if version.is_empty() {
+        return None;
+    }
+
+    Some(OsRelease {
+        name: "Android".to_string(),
+        pretty_name: Some(format!("Android {}", version)),
+        id: Some("android".to_string()),
+        version_id: Some(version),
+        ..Default::default()
+    })
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_os_release() -> OsRelease {
+    file::read("/etc/os-release")
+        .or_else(|| file::read("/usr/lib/os-release"))
+        .map(|content| from_os_release(&content))
+        .or_else(|| file::read("/etc/lsb-release").and_then(|content| from_lsb_release(&content)))
+        .or_else(from_release_file)
+        .or_else(from_getprop)
+        .unwrap_or_else(|| OsRelease {
+            name: "Linux".to_string(),
+            ..Default::default()
+        })
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_distro() -> String {
+    let release = get_os_release();
+
+    let mut distro = release.pretty_name.clone().unwrap_or_else(|| {
+        match &release.version {
+            Some(version) => format!("{} {}", release.name, version),
+            None => release.name.clone(),
+        }
+    });
+
+    // Rolling releases such as Arch only carry a BUILD_ID.
+    if release.version_id.is_none() {
+        if let Some(build_id) = &release.build_id {
+            distro.push_str(&format!(" ({})", build_id));
+        }
+    }
+
+    distro
+}
+
+// Maps the distro's ANSI_COLOR (e.g. "1;34" or "0;38;2;23;147;209") to
+// the closest named color, truecolor values are ignored.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_accent_color() -> Option<String> {
+    const NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+    let ansi_color = get_os_release().ansi_color?;
+    let codes = ansi_color.split(';')
+        .filter_map(|code| code.trim().parse::<usize>().ok())
+        .collect::<Vec<usize>>();
+
+    if codes.contains(&38) {
+        return None;
+    }
+
+    codes.iter().find_map(|&code| match code {
+        30..=37 => Some(NAMES[code - 30].to_string()),
+        90..=97 => Some(format!("bright_{}", NAMES[code - 90])),
+        _ => None,
+    })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn get_accent_color() -> Option<String> {
+    None
+}
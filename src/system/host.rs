@@ -1,205 +1,142 @@
-#[cfg(any(target_os = "windows", target_os = "linux"))]
-use std::process::Command;
+use crate::helpers::command;
 #[cfg(any(target_os = "linux", target_os = "android"))]
-use {
-    crate::helpers::file::file_open,
-    std::{fs::File, io::Read},
-};
+use crate::helpers::{file, process};
 
-#[cfg(target_os = "windows")]
-pub fn get_hostname() -> String {
-    let mut hostname = String::new();
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub const SHELLS: [&str; 15] = [
+    "sh", "bash", "zsh", "fish", "nu", "elvish", "dash", "ksh", "mksh", "tcsh",
+    "csh", "xonsh", "pwsh", "ion", "oksh",
+];
 
-    let output = Command::new("reg")
-        .args(&["query", "HKLM\\SYSTEM\\CurrentControlSet\\Control\\ComputerName\\ComputerName", "/v", "ComputerName"])
-        .output()
-        .expect("Failed to execute process");
+// Processes that may sit between the shell and us without being either.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub const WRAPPERS: [&str; 7] = [
+    "sudo", "doas", "su", "login", "script", "cargo", "boykisserfetch",
+];
 
-    let output = String::from_utf8_lossy(&output.stdout);
+// "reg query ... /v ComputerName"
+pub fn get_reg_hostname() -> Option<String> {
+    let key = "HKLM\\SYSTEM\\CurrentControlSet\\Control\\ComputerName\\ComputerName";
+    let output = command::run("reg", &["query", key, "/v", "ComputerName"])?;
 
-    for line in output.lines() {
-        if line.contains("ComputerName") {
-            hostname = line.split_whitespace().last().unwrap().to_string();
-        }
-    }
+    command::reg_values(&output, "ComputerName").pop()
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_hostname() -> String {
+    get_reg_hostname().unwrap_or_else(|| "Unknown".to_string())
+}
+
+pub fn get_scutil_hostname() -> Option<String> {
+    let output = command::run("scutil", &["--get", "ComputerName"])?;
 
-    hostname
+    Some(output.trim().to_string()).filter(|name| !name.is_empty())
 }
 
 #[cfg(target_os = "macos")]
 pub fn get_hostname() -> String {
-    // macOS-compatible implementation
-    use std::process::Command;
+    get_scutil_hostname().unwrap_or_else(|| "unknown-macos".to_string())
+}
 
-    let output = Command::new("scutil")
-        .arg("--get")
-        .arg("ComputerName")
-        .output()
-        .ok()
-        .and_then(|o| String::from_utf8(o.stdout).ok());
+// The user name is the last component of the last profile's image path.
+pub fn get_reg_user() -> Option<String> {
+    let key = "HKLM\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\ProfileList";
+    let output = command::run("reg", &["query", key, "/s"])?;
 
-    output.unwrap_or_else(|| "unknown-macos".to_string()).trim().to_string()
+    command::reg_values(&output, "ProfileImagePath")
+        .pop()
+        .and_then(|path| path.rsplit('\\').next().map(str::to_string))
 }
 
 #[cfg(target_os = "windows")]
 pub fn get_user() -> String {
-    let mut user = String::new();
-
-    let output = Command::new("reg")
-        .args(&["query", "HKLM\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\ProfileList", "/s"])
-        .output()
-        .expect("Failed to execute process");
+    get_reg_user().unwrap_or_else(|| "Unknown".to_string())
+}
 
-    let output = String::from_utf8_lossy(&output.stdout);
+// PowerShell sets the console font to "Lucida Console", cmd doesn't.
+pub fn get_reg_shell() -> Option<String> {
+    let output = command::run("reg", &["query", "HKCU\\Console", "/v", "FaceName"])?;
 
-    for line in output.lines() {
-        if line.contains("ProfileImagePath") {
-            user = line.split_whitespace().last().unwrap().to_string();
-            user = user.split("\\").last().unwrap().to_string();
-        }
+    match command::reg_values(&output, "FaceName").pop().as_deref() {
+        Some("Lucida Console") => Some("PowerShell".to_string()),
+        _ => Some("CMD".to_string()),
     }
-
-    user
 }
 
 #[cfg(target_os = "windows")]
 pub fn get_shell() -> String {
-    let mut shell = String::new();
-
-    let output = Command::new("reg")
-        .args(&["query", "HKCU\\Console", "/v", "FaceName"])
-        .output()
-        .expect("Failed to execute process");
-
-    let output = String::from_utf8_lossy(&output.stdout);
-
-    for line in output.lines() {
-        if line.contains("FaceName") {
-            shell = line.split_whitespace().last().unwrap().to_string();
-        }
-    }
+    get_reg_shell().unwrap_or_else(|| "CMD".to_string())
+}
 
-    if shell == "Lucida Console" {
-        shell = "PowerShell".to_string();
-    } else {
-        shell = "CMD".to_string();
+// One "WxH" per video controller that drives a display.
+pub fn get_wmic_resolution() -> Option<String> {
+    let output = command::run("wmic", &[
+        "path",
+        "Win32_VideoController",
+        "get",
+        "CurrentVerticalResolution,CurrentHorizontalResolution",
+        "/format:value"
+    ])?;
+
+    let horizontal = command::wmic_values(&output, "CurrentHorizontalResolution");
+    let vertical = command::wmic_values(&output, "CurrentVerticalResolution");
+
+    let monitors = horizontal.iter()
+        .zip(vertical.iter())
+        .filter(|(width, height)| !width.is_empty() && !height.is_empty())
+        .map(|(width, height)| format!("{}x{}", width, height))
+        .collect::<Vec<String>>();
+
+    match monitors.is_empty() {
+        true => None,
+        false => Some(monitors.join(", ")),
     }
-
-    shell
 }
 
 #[cfg(target_os = "windows")]
 pub fn get_resolution() -> String {
-    let mut temp_horiz = String::new();
-    let mut temp_vert = String::new();
-
-    let output = Command::new("wmic")
-        .args(&[
-            "path", 
-            "Win32_VideoController", 
-            "get", 
-            "CurrentVerticalResolution,CurrentHorizontalResolution", 
-            "/format:value"
-        ])
-        .output()
-        .expect("Failed to execute process");
-
-    let output = String::from_utf8_lossy(&output.stdout);
-
-    let split_by_equals = |item: &str| -> String {
-        item.split("=")
-            .collect::<Vec<&str>>()
-            .iter()
-            .nth(1)
-            .unwrap()
-            .trim()
-            .to_string()
-    };
+    get_wmic_resolution().unwrap_or_else(|| "Unknown".to_string())
+}
 
-    for line in output.lines() {
-        if line.contains("CurrentHorizontalResolution") {
-            temp_horiz = split_by_equals(line);
-        }
+// Finder reports the desktop bounds as "0, 0, width, height".
+pub fn get_osascript_resolution() -> Option<String> {
+    let output = command::run("osascript", &[
+        "-e",
+        "tell application \"Finder\" to get bounds of window of desktop"
+    ])?;
 
-        if line.contains("CurrentVerticalResolution") {
-            temp_vert = split_by_equals(line);
-        }
-    }
+    let parts = output.trim().split(',').map(str::trim).collect::<Vec<&str>>();
 
-    format!(
-        "{}x{}", 
-        temp_horiz, 
-        temp_vert
-    ).to_string()
+    match parts.as_slice() {
+        [_, _, width, height] => Some(format!("{}x{}", width, height)),
+        _ => None,
+    }
 }
 
 #[cfg(target_os = "macos")]
 pub fn get_resolution() -> String {
-    use std::process::Command;
-    let output = Command::new("osascript")
-        .arg("-e")
-        .arg("tell application \"Finder\" to get bounds of window of desktop")
-        .output();
-
-    match output {
-        Ok(o) => {
-            let out = String::from_utf8_lossy(&o.stdout);
-            // Output is: 0, 0, width, height
-            let parts: Vec<&str> = out.trim().split(',').collect();
-            if parts.len() == 4 {
-                let w = parts[2].trim();
-                let h = parts[3].trim();
-                format!("{}x{}", w, h)
-            } else {
-                "unknown".to_string()
-            }
-        }
-        Err(_) => "unknown".to_string(),
-    }
+    get_osascript_resolution().unwrap_or_else(|| "unknown".to_string())
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
 pub fn get_hostname() -> String {
-    Command::new("hostname")
-        .output()
-        .expect("Failed to execute process")
-        .stdout
-        .iter()
-        .map(|&c| c as char)
-        .collect::<String>()
-        .trim()
-        .to_string()
+    command::run("hostname", &[])
+        .map(|hostname| hostname.trim().to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
 pub fn get_kernel() -> String {
-    let mut kernel = String::new();
-
-    let output = Command::new("uname")
-        .args(&["-r"])
-        .output()
-        .expect("Failed to execute process");
-
-    let output = String::from_utf8_lossy(&output.stdout);
-
-    for line in output.lines() {
-        kernel = line.to_string();
-    }
-
-    kernel
+    command::run("uname", &["-r"])
+        .and_then(|output| output.lines().last().map(str::to_string))
+        .unwrap_or_else(|| "Unknown".to_string())
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
 pub fn get_user() -> String {
-    Command::new("whoami")
-        .output()
-        .expect("Failed to execute whoami")
-        .stdout
-        .iter()
-        .map(|&c| c as char)
-        .collect::<String>()
-        .trim()
-        .to_string()
+    command::run("whoami", &[])
+        .map(|user| user.trim().to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
 }
 
 #[cfg(target_os = "macos")]
@@ -207,57 +144,87 @@ pub fn get_user() -> String {
     std::env::var("USER").unwrap_or_else(|_| "unknown-user".into())
 }
 
-#[cfg(any(target_os = "linux", target_os = "android"))] 
-pub fn get_distro() -> String {
-    use std::rc::Rc;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_login_shell() -> Option<String> {
+    use std::ffi::CStr;
+
+    // SAFETY: getpwuid returns either null or a pointer to a static passwd
+    // entry which stays valid until the next getpw* call on this thread.
+    unsafe {
+        let passwd = libc::getpwuid(libc::getuid());
+
+        if passwd.is_null() || (*passwd).pw_shell.is_null() {
+            return None;
+        }
 
-    let mut distro: Rc<String> = Rc::new(String::new());
-    let mut temp_buf: String = String::new();
+        Some(CStr::from_ptr((*passwd).pw_shell).to_string_lossy().to_string())
+    }
+}
 
-    let mut file = File::open("/etc/os-release").unwrap();
-    file.read_to_string(&mut temp_buf).unwrap();
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_running_shell() -> Option<String> {
+    for pid in process::get_ancestors() {
+        let name = process::get_name(pid)?;
 
-    let lines: &Vec<&str> = &temp_buf.lines().collect();
-    
-    lines.into_iter().for_each(|line| {
-        if line.contains("PRETTY_NAME") {
-            distro = Rc::new(
-                line.split("=")
-                    .collect::<Vec<&str>>()[1].to_string()
-                    .replace("\"", "")
-            );
+        if SHELLS.contains(&name.as_str()) {
+            return process::get_exe(pid).or(Some(name));
         }
 
-        if line.contains("BUILD_ID") {
-            distro = Rc::new(
-                format!("{} ({})", distro, 
-                    line.split("=")
-                        .collect::<Vec<&str>>()[1].to_string()
-                        .replace("\"", "")
-                )
-            );
+        // Anything that is neither a shell nor a known wrapper means we were
+        // not started from an interactive shell.
+        if !WRAPPERS.contains(&name.as_str()) {
+            break;
         }
-    });
+    }
 
-    distro.to_string()
+    None
 }
 
+// Runs `program flag` and returns the first token of the first output line
+// that starts with a digit, e.g. "GNU bash, version 5.2.15(1)-release",
+// "xonsh/0.14.0" or "systemd 252 (252.22-1~deb12u1)".
 #[cfg(any(target_os = "linux", target_os = "android"))]
-pub fn get_shell() -> String {
-    let temp_buf: String = file_open("/etc/passwd");
-    let mut final_str = String::new();
+fn get_program_version(program: &str, flag: &str) -> Option<String> {
+    let output = command::run(program, &[flag])?;
+
+    output.lines()
+        .next()?
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '/')
+        .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))
+        .map(|word| {
+            word.chars()
+                .take_while(|c| c.is_ascii_digit() || *c == '.')
+                .collect::<String>()
+                .trim_end_matches('.')
+                .to_string()
+        })
+        .filter(|version| !version.is_empty())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_shell_version(path: &str, name: &str) -> Option<String> {
+    let flag = match name {
+        "dash" | "sh" | "ksh" | "mksh" | "csh" => return None,
+        "elvish" => "-version",
+        _ => "--version",
+    };
 
-    let lines: &Vec<&str> = &temp_buf.lines().collect();
+    get_program_version(path, flag)
+}
 
-    lines.into_iter().for_each(|line| {
-        if line.contains(&get_user()) {
-            final_str = line.split(":")
-                .collect::<Vec<&str>>()[6]
-                .to_string();
-        }
-    });
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_shell() -> String {
+    let path = match get_running_shell().or_else(get_login_shell) {
+        Some(path) => path,
+        None => return "Unknown".to_string(),
+    };
 
-    final_str
+    let name = path.rsplit('/').next().unwrap_or(&path).to_string();
+
+    match get_shell_version(&path, &name) {
+        Some(version) => format!("{} {}", name, version),
+        None => name,
+    }
 }
 
 #[cfg(target_os = "macos")]
@@ -266,37 +233,324 @@ pub fn get_shell() -> String {
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
-pub fn get_resolution() -> String {
-    let mut final_str = String::new();
+type MonitorSource = fn() -> Option<Vec<String>>;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn format_mode(mode: &str, refresh: Option<&str>) -> String {
+    let mode = mode.trim().trim_end_matches("px").trim();
+
+    match refresh.and_then(|rate| rate.trim().trim_end_matches("Hz").trim().parse::<f64>().ok()) {
+        Some(rate) => format!("{} @ {}Hz", mode, rate.round()),
+        None => mode.to_string(),
+    }
+}
+
+// Hyprland: "\t2560x1440@143.99800 at 0x0" under every "Monitor" block.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_hyprctl_monitors() -> Option<Vec<String>> {
+    let output = command::run("hyprctl", &["monitors"])?;
+    let mut next = false;
+    let mut monitors = Vec::new();
+
+    for line in output.lines() {
+        if next {
+            let mode = line.split_whitespace().next().unwrap_or("");
+            let mut split = mode.split('@');
+
+            if let Some(resolution) = split.next() {
+                monitors.push(format_mode(resolution, split.next()));
+            }
+        }
+
+        next = line.starts_with("Monitor ");
+    }
+
+    Some(monitors)
+}
+
+// Sway: "  Current mode: 2560x1440 @ 143.998 Hz".
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_swaymsg_monitors() -> Option<Vec<String>> {
+    let output = command::run("swaymsg", &["-p", "-t", "get_outputs"])?;
+
+    Some(
+        output.lines()
+            .filter_map(|line| line.trim().strip_prefix("Current mode:"))
+            .map(|mode| {
+                let mut split = mode.split('@');
+                format_mode(split.next().unwrap_or(""), split.next())
+            })
+            .collect()
+    )
+}
+
+// wlroots compositors: "    2560x1440 px, 143.998001 Hz (preferred, current)".
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_wlr_randr_monitors() -> Option<Vec<String>> {
+    let output = command::run("wlr-randr", &[])?;
+
+    Some(
+        output.lines()
+            .filter(|line| line.contains("current"))
+            .map(|line| {
+                let mut split = line.split(',');
+                format_mode(
+                    split.next().unwrap_or(""),
+                    split.next().and_then(|rate| rate.split('(').next())
+                )
+            })
+            .collect()
+    )
+}
+
+// X11: the active mode of every connected output is marked with '*'.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_xrandr_monitors() -> Option<Vec<String>> {
+    let output = command::run("xrandr", &["--current"])?;
+    let mut connected = false;
+    let mut monitors = Vec::new();
 
-    let output = Command::new("xrandr")
-        .output()
-        .expect("Failed to execute xrandr");
+    for line in output.lines() {
+        if !line.starts_with(' ') {
+            connected = line.contains(" connected");
+            continue;
+        }
 
-    let output = String::from_utf8(output.stdout).unwrap();
+        if !connected {
+            continue;
+        }
 
-    let lines: &Vec<&str> = &output.lines().collect();
+        let mut split = line.split_whitespace();
+        let mode = split.next().unwrap_or("");
 
-    lines.into_iter().for_each(|line| {
-        if line.contains(" connected") {
-            final_str = line.split(" ")
-                .collect::<Vec<&str>>()[2]
-                .to_string();
+        if let Some(rate) = split.find(|rate| rate.contains('*')) {
+            monitors.push(format_mode(mode, Some(rate.trim_end_matches(['*', '+']))));
         }
-    });
+    }
+
+    Some(monitors)
+}
+
+// Kernel mode setting, works without any display server but only knows the
+// preferred mode of every connected connector, not its refresh rate.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_drm_monitors() -> Option<Vec<String>> {
+    let connectors = file::read_dir("/sys/class/drm");
+
+    Some(
+        connectors.iter()
+            .filter(|path| {
+                file::read(&format!("{}/status", path))
+                    .map(|status| status.trim() == "connected")
+                    .unwrap_or(false)
+            })
+            .filter_map(|path| {
+                file::read(&format!("{}/modes", path))?
+                    .lines()
+                    .next()
+                    .map(|mode| format_mode(mode, None))
+            })
+            .collect()
+    )
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_resolution() -> String {
+    let has_env = |name: &str| std::env::var_os(name).is_some();
+
+    let sources: [(bool, MonitorSource); 5] = [
+        (has_env("HYPRLAND_INSTANCE_SIGNATURE"), get_hyprctl_monitors),
+        (has_env("SWAYSOCK"), get_swaymsg_monitors),
+        (has_env("WAYLAND_DISPLAY"), get_wlr_randr_monitors),
+        (has_env("DISPLAY"), get_xrandr_monitors),
+        (true, get_drm_monitors),
+    ];
+
+    sources.iter()
+        .filter(|(available, _)| *available)
+        .filter_map(|(_, source)| source())
+        .find(|monitors| !monitors.is_empty())
+        .map(|monitors| monitors.join(", "))
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn count_systemd_units(state: &str) -> usize {
+    let state = format!("--state={}", state);
+
+    command::run("systemctl", &["list-units", "--type=service", "--no-legend", "--plain", &state])
+        .map(|output| output.lines().filter(|line| !line.trim().is_empty()).count())
+        .unwrap_or(0)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn count_dir_entries(path: &str) -> Option<usize> {
+    match file::exists(path) {
+        true => Some(file::read_dir(path).len()),
+        false => None,
+    }
+}
+
+// The init system and its version, followed by how many services it runs
+// when `services` is set. Counting systemd's means running systemctl twice.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_init_system(services: bool) -> String {
+    let exists = file::exists;
+
+    // /proc/1/exe is usually only readable by root, /proc/1/comm isn't.
+    let exe = process::get_exe(1).unwrap_or_default();
+    let comm = process::get_name(1).unwrap_or_default();
+
+    let (name, version, counts) = if exists("/run/systemd/system") {
+        let (running, failed) = match services {
+            true => (count_systemd_units("running"), count_systemd_units("failed")),
+            false => (0, 0),
+        };
+
+        let counts = match (running, failed) {
+            (0, 0) => None,
+            (running, 0) => Some(format!("{} running", running)),
+            (running, failed) => Some(format!("{} running, {} failed", running, failed)),
+        };
+
+        ("systemd", get_program_version("systemctl", "--version"), counts)
+    } else if exists("/run/openrc") {
+        let counts = count_dir_entries("/run/openrc/started")
+            .map(|started| format!("{} started", started));
+
+        ("OpenRC", get_program_version("openrc", "--version"), counts)
+    } else if exists("/run/runit") || comm == "runit" {
+        let counts = count_dir_entries("/run/runit/service")
+            .or_else(|| count_dir_entries("/var/service"))
+            .map(|services| format!("{} services", services));
+
+        ("runit", None, counts)
+    } else if exists("/run/s6") || exists("/run/s6-rc") || comm.starts_with("s6-") {
+        ("s6", None, None)
+    } else if exists("/run/dinitctl") || comm == "dinit" {
+        ("dinit", get_program_version("dinit", "--version"), None)
+    } else if exe.ends_with("busybox") {
+        ("BusyBox init", None, None)
+    } else if comm == "init" && exists("/run/initctl") {
+        ("SysVinit", None, None)
+    } else if !comm.is_empty() {
+        (comm.as_str(), None, None)
+    } else {
+        ("Unknown", None, None)
+    };
+
+    let mut final_str = name.to_string();
+
+    if let Some(version) = version {
+        final_str.push_str(&format!(" {}", version));
+    }
+
+    if let Some(counts) = counts.filter(|_| services) {
+        final_str.push_str(&format!(" ({})", counts));
+    }
 
     final_str
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
-pub fn get_init_system() -> String {
-    Command::new("ps")
-        .args(&["-p", "1", "-o", "comm="])
-        .output()
-        .unwrap()
-        .stdout
-        .iter()
-        .map(|&c| c as char)
-        .collect::<String>()
-        .trim().to_string()
+pub fn get_uptime_seconds() -> Option<u64> {
+    file::read("/proc/uptime")?
+        .split_whitespace()
+        .next()?
+        .parse::<f64>()
+        .ok()
+        .map(|seconds| seconds as u64)
+}
+
+// "{ sec = 1700000000, usec = 123456 } Tue Nov 14 22:13:20 2023"
+pub fn get_sysctl_boot_time() -> Option<i64> {
+    let output = command::run("sysctl", &["-n", "kern.boottime"])?;
+
+    output.split("sec =")
+        .nth(1)?
+        .split(',')
+        .next()?
+        .trim()
+        .parse::<i64>()
+        .ok()
+}
+
+#[cfg(target_os = "macos")]
+pub fn get_uptime_seconds() -> Option<u64> {
+    let boot_time = get_sysctl_boot_time()?;
+
+    Some((crate::helpers::time::now() - boot_time).max(0) as u64)
+}
+
+pub fn get_wmic_uptime_seconds() -> Option<u64> {
+    let output = command::run("wmic", &[
+        "path", "Win32_PerfFormattedData_PerfOS_System", "get", "SystemUpTime"
+    ])?;
+
+    command::wmic_rows(&output).first()?.parse::<u64>().ok()
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_uptime_seconds() -> Option<u64> {
+    get_wmic_uptime_seconds()
+}
+
+pub fn format_uptime(seconds: u64, style: &str) -> String {
+    let days = seconds / 86400;
+    let hours = seconds % 86400 / 3600;
+    let minutes = seconds % 3600 / 60;
+
+    match style {
+        "seconds" => format!("{} seconds", seconds),
+
+        "short" => {
+            let parts = [(days, "d"), (hours, "h"), (minutes, "m")]
+                .iter()
+                .filter(|(value, _)| *value > 0)
+                .map(|(value, unit)| format!("{}{}", value, unit))
+                .collect::<Vec<String>>();
+
+            if parts.is_empty() {
+                return "0m".to_string();
+            }
+
+            parts.join(" ")
+        },
+
+        _ => {
+            let parts = [(days, "day"), (hours, "hour"), (minutes, "min")]
+                .iter()
+                .filter(|(value, _)| *value > 0)
+                .map(|(value, unit)| {
+                    format!("{} {}{}", value, unit, if *value == 1 { "" } else { "s" })
+                })
+                .collect::<Vec<String>>();
+
+            if parts.is_empty() {
+                return "0 mins".to_string();
+            }
+
+            parts.join(", ")
+        }
+    }
+}
+
+pub fn get_uptime(style: &str) -> String {
+    match get_uptime_seconds() {
+        Some(seconds) => format_uptime(seconds, style),
+        None => "Unknown".to_string(),
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_boot_time() -> String {
+    let btime = file::read("/proc/stat")
+        .and_then(|stat| {
+            stat.lines()
+                .find_map(|line| line.strip_prefix("btime "))
+                .and_then(|btime| btime.trim().parse::<i64>().ok())
+        });
+
+    btime.and_then(|btime| crate::helpers::time::format_timestamp(btime, "%Y-%m-%d %H:%M"))
+        .unwrap_or_else(|| "Unknown".to_string())
 }
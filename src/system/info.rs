@@ -0,0 +1,100 @@
+use crate::modules::{registry::Registry, Context};
+
+#[cfg(feature = "host")]
+#[derive(Clone, Debug, Default)]
+pub struct HostInfo {
+    pub user: String,
+    pub hostname: String,
+    pub distro: Option<String>,
+    pub kernel: Option<String>,
+    pub shell: Option<String>,
+    pub terminal: Option<String>,
+    pub resolution: Option<String>,
+    pub init: Option<String>,
+    pub uptime_seconds: Option<u64>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+}
+
+#[cfg(feature = "specs")]
+#[derive(Clone, Debug, Default)]
+pub struct SpecsInfo {
+    pub model: Option<String>,
+    pub arch: Option<String>,
+    pub cpu: Option<String>,
+    pub gpus: Vec<String>,
+    pub memory: Option<String>,
+    pub virtualization: Option<String>,
+}
+
+#[cfg(feature = "net")]
+#[derive(Clone, Debug, Default)]
+pub struct NetInfo {
+    pub ip: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SystemInfo {
+    #[cfg(feature = "host")]
+    pub host: HostInfo,
+    #[cfg(feature = "specs")]
+    pub specs: SpecsInfo,
+    #[cfg(feature = "net")]
+    pub net: NetInfo,
+}
+
+#[allow(dead_code)]
+fn text(registry: &Registry, ctx: &Context, id: &str) -> Option<String> {
+    registry.collect(id, ctx).ok().map(|value| value.to_string())
+}
+
+impl SystemInfo {
+    pub fn collect() -> SystemInfo {
+        SystemInfo::collect_with(&Registry::builtin(), &Context::default())
+    }
+
+    // Fields are left empty for modules the registry doesn't provide on
+    // this platform or whose collector failed.
+    #[allow(unused_variables)]
+    pub fn collect_with(registry: &Registry, ctx: &Context) -> SystemInfo {
+        SystemInfo {
+            #[cfg(feature = "host")]
+            host: HostInfo {
+                user: crate::system::host::get_user(),
+                hostname: crate::system::host::get_hostname(),
+                distro: text(registry, ctx, "distro"),
+                kernel: text(registry, ctx, "kernel"),
+                shell: text(registry, ctx, "shell"),
+                terminal: text(registry, ctx, "terminal"),
+                resolution: text(registry, ctx, "resolution"),
+                init: text(registry, ctx, "init"),
+                uptime_seconds: crate::system::host::get_uptime_seconds(),
+                locale: text(registry, ctx, "locale"),
+                timezone: text(registry, ctx, "timezone"),
+            },
+
+            #[cfg(feature = "specs")]
+            specs: SpecsInfo {
+                model: text(registry, ctx, "host"),
+                arch: text(registry, ctx, "arch"),
+                cpu: text(registry, ctx, "cpu"),
+                gpus: match registry.collect("gpu", ctx) {
+                    Ok(crate::modules::ModuleValue::List(gpus)) => gpus,
+                    Ok(crate::modules::ModuleValue::Text(gpu)) => vec![gpu],
+                    Err(_) => Vec::new(),
+                },
+                memory: text(registry, ctx, "memory"),
+                virtualization: text(registry, ctx, "virtualization"),
+            },
+
+            #[cfg(feature = "net")]
+            net: NetInfo {
+                ip: text(registry, ctx, "ip"),
+            },
+        }
+    }
+}
+
+pub fn collect() -> SystemInfo {
+    SystemInfo::collect()
+}
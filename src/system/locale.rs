@@ -0,0 +1,85 @@
+#[cfg(unix)]
+use crate::helpers::time::{format_timestamp, now};
+
+// Categories shown when they differ from LANG.
+const CATEGORIES: [&str; 6] = [
+    "LC_CTYPE",
+    "LC_NUMERIC",
+    "LC_TIME",
+    "LC_COLLATE",
+    "LC_MONETARY",
+    "LC_MESSAGES",
+];
+
+pub fn get_locale() -> String {
+    resolve_locale(|name| std::env::var(name).ok())
+}
+
+// LC_ALL overrides every category, otherwise LANG is the locale of all
+// categories that aren't set themselves. Those are listed after it.
+pub fn resolve_locale<F: Fn(&str) -> Option<String>>(var: F) -> String {
+    let var = |name: &str| var(name).filter(|value| !value.is_empty());
+
+    if let Some(all) = var("LC_ALL") {
+        return all;
+    }
+
+    let lang = var("LANG").unwrap_or_else(|| "C".to_string());
+    let categories = CATEGORIES.iter()
+        .filter_map(|name| var(name).filter(|value| *value != lang).map(|value| (name, value)))
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<String>>();
+
+    match categories.is_empty() {
+        true => lang,
+        false => format!("{} ({})", lang, categories.join(", ")),
+    }
+}
+
+#[cfg(unix)]
+fn get_timezone_name() -> Option<String> {
+    // TZ may be ":Europe/Berlin", a plain zone name or a POSIX rule.
+    if let Ok(tz) = std::env::var("TZ") {
+        let tz = tz.trim_start_matches(':');
+
+        if !tz.is_empty() {
+            return Some(tz.trim_start_matches("/usr/share/zoneinfo/").to_string());
+        }
+    }
+
+    get_system_timezone()
+}
+
+// The zone /etc/localtime links to. Copies of the zone file (containers,
+// Debian) have the name in /etc/timezone instead.
+#[cfg(unix)]
+pub fn get_system_timezone() -> Option<String> {
+    let zone = crate::helpers::file::read_link("/etc/localtime").and_then(|target| {
+        target.to_string_lossy()
+            .split_once("zoneinfo/")
+            .map(|(_, zone)| zone.to_string())
+    });
+
+    zone.or_else(|| {
+        crate::helpers::file::read("/etc/timezone")
+            .map(|zone| zone.trim().to_string())
+            .filter(|zone| !zone.is_empty())
+    })
+}
+
+#[cfg(unix)]
+pub fn get_timezone() -> String {
+    let offset = format_timestamp(now(), "%Z, %z");
+
+    match (get_timezone_name(), offset) {
+        (Some(name), Some(offset)) => format!("{} ({})", name, offset),
+        (Some(name), None) => name,
+        (None, Some(offset)) => offset,
+        (None, None) => "Unknown".to_string(),
+    }
+}
+
+#[cfg(unix)]
+pub fn get_datetime(format: &str) -> String {
+    format_timestamp(now(), format).unwrap_or_else(|| "Unknown".to_string())
+}
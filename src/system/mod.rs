@@ -1,3 +1,16 @@
+pub mod custom;
+#[cfg(feature = "host")]
+pub mod distro;
+#[cfg(feature = "host")]
 pub mod host;
+pub mod info;
+#[cfg(feature = "host")]
+pub mod locale;
+#[cfg(feature = "net")]
 pub mod net;
-pub mod specs;
\ No newline at end of file
+#[cfg(feature = "specs")]
+pub mod specs;
+#[cfg(feature = "host")]
+pub mod terminal;
+#[cfg(feature = "specs")]
+pub mod virt;
\ No newline at end of file
@@ -1,29 +1,24 @@
 #[cfg(any(target_os = "linux", target_os = "android"))]
 use {
-    crate::helpers::file::file_open,
-    std::sync::Mutex,
-    std::ops::Add,
+    crate::helpers::file,
+    std::net::Ipv4Addr,
 };
-use std::process::Command;
+use crate::helpers::command;
 
-#[cfg(target_os = "windows")]
-pub fn get_ipaddr() -> String {
-    let mut ipaddr = String::new();
-
-    let output = Command::new("ipconfig")
-        .args(&["/all"])
-        .output()
-        .expect("Failed to execute process");
-
-    let output = String::from_utf8_lossy(&output.stdout);
+// "   IPv4 Address. . . . . . . . . . . : 192.168.1.42(Preferred)"
+pub fn get_ipconfig_addr() -> Option<String> {
+    let output = command::run("ipconfig", &["/all"])?;
 
-    for line in output.lines() {
-        if line.contains("IPv4 Address") {
-            ipaddr = line.split_whitespace().last().unwrap().to_string();
-        }
-    }
+    output.lines()
+        .filter(|line| line.contains("IPv4 Address"))
+        .filter_map(|line| line.split(" : ").nth(1))
+        .map(|addr| addr.trim().trim_end_matches("(Preferred)").to_string())
+        .next_back()
+}
 
-    ipaddr
+#[cfg(target_os = "windows")]
+pub fn get_ipaddr() -> String {
+    get_ipconfig_addr().unwrap_or_default()
 }
 
 #[cfg(target_os = "macos")]
@@ -38,66 +33,148 @@ pub fn get_ipaddr() -> String {
         .unwrap_or_else(|_| "unknown".into())
 }
 
+// Parses a little-endian hex address from /proc/net/route.
 #[cfg(any(target_os = "linux", target_os = "android"))]
-pub fn get_ipaddr() -> String {
-    let final_str: Mutex<String> = Mutex::new(String::new());
-    let intr = file_open("/proc/net/route");
+fn parse_route_addr(hex: &str) -> Option<Ipv4Addr> {
+    let value = u32::from_str_radix(hex, 16).ok()?;
 
-    let lines: &Vec<&str> = &intr.lines().collect();
-    let mut interface = String::new();
+    Some(Ipv4Addr::from(value.to_le_bytes()))
+}
 
-    lines.into_iter().for_each(|line| {
-        if line.contains("00000000") {
-            interface = line.split("\t").collect::<Vec<&str>>()[0].to_string();
-        }
-    });
-
-    let output = Command::new("ifconfig")
-        .arg(interface.clone())
-        .output();
-    
-    if output.is_err() {
-        return String::from("Unknown");
-    };
+// Returns the interface of the default route and the (destination, mask)
+// pairs of every route that goes through it.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_default_routes() -> Option<(String, Vec<(Ipv4Addr, Ipv4Addr)>)> {
+    let routes = file::file_open("/proc/net/route")
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+
+            Some((
+                fields.first()?.to_string(),
+                parse_route_addr(fields.get(1)?)?,
+                parse_route_addr(fields.get(7)?)?,
+            ))
+        })
+        .collect::<Vec<(String, Ipv4Addr, Ipv4Addr)>>();
+
+    let interface = routes.iter()
+        .find(|(_, destination, mask)| destination.is_unspecified() && mask.is_unspecified())
+        .map(|(interface, _, _)| interface.clone())?;
 
-    let unw = output.unwrap().stdout;
-    
-    let stdout = String::from_utf8_lossy(&unw);
+    let subnets = routes.into_iter()
+        .filter(|(name, _, mask)| *name == interface && !mask.is_unspecified())
+        .map(|(_, destination, mask)| (destination, mask))
+        .collect();
 
-    let lines: &Vec<&str> = &stdout.lines().clone().collect();
+    Some((interface, subnets))
+}
+
+// Addresses the kernel marks as "/32 host LOCAL" in the routing trie.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_local_addrs() -> Vec<Ipv4Addr> {
+    let fib_trie = file::file_open("/proc/net/fib_trie");
+    let lines = fib_trie.lines().map(str::trim).collect::<Vec<&str>>();
+
+    let mut addrs = lines.windows(2)
+        .filter(|pair| pair[1].starts_with("/32 host LOCAL"))
+        .filter_map(|pair| pair[0].strip_prefix("|-- ")?.parse::<Ipv4Addr>().ok())
+        .filter(|addr| !addr.is_loopback())
+        .collect::<Vec<Ipv4Addr>>();
+
+    addrs.dedup();
+    addrs
+}
 
-    let mut next: bool = false;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_ifconfig_addr(interface: &str) -> Option<String> {
+    let output = command::run("ifconfig", &[interface])?;
+
+    output.lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix("inet "))
+        .and_then(|rest| rest.split_whitespace().next())
+        .map(|addr| addr.trim_start_matches("addr:").to_string())
+}
 
-    let process_ip = |line: &str| {
-        let ip = line.split(" ").collect::<Vec<&str>>()[1].to_string();
-        final_str.lock().unwrap().push_str(&ip);
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_ipaddr() -> String {
+    let (interface, subnets) = match get_default_routes() {
+        Some(routes) => routes,
+        None => return "Unknown".to_string(),
     };
 
-    lines.into_iter().for_each(|line| {
-        if next {
-            line.replace("\t", "")
-                .split("  ")
-                .collect::<Vec<&str>>()
-                .into_iter()
-                .for_each(|item| {
-                    if item.contains("inet") {
-                        process_ip(item);
-                    }
-                });
-
-            next = false;
-        }
+    let in_subnet = |addr: &Ipv4Addr| {
+        subnets.iter().any(|(destination, mask)| {
+            u32::from(*addr) & u32::from(*mask) == u32::from(*destination)
+        })
+    };
+
+    let addr = get_local_addrs()
+        .into_iter()
+        .find(in_subnet)
+        .map(|addr| addr.to_string())
+        .or_else(|| get_ifconfig_addr(&interface));
+
+    match addr {
+        Some(addr) => format!("{} ({})", addr, interface),
+        None => "Unknown".to_string(),
+    }
+}
+
+// (received, transmitted) bytes of the default route's interface.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub type NetBytes = Option<(String, u64, u64)>;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn sample_net_bytes() -> NetBytes {
+    let (interface, _) = get_default_routes()?;
+    let dev = file::file_open("/proc/net/dev");
+
+    // "  eth0: 13091039    1740    0 ...", bytes are the 1st and 9th field.
+    let fields = dev.lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim() == interface)
+        .map(|(_, fields)| fields.split_whitespace().collect::<Vec<&str>>())?;
+
+    Some((interface, fields.first()?.parse().ok()?, fields.get(8)?.parse().ok()?))
+}
+
+// Time `sample_net_bytes` needs between samples for a meaningful rate.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub const NET_SAMPLE_TIME: std::time::Duration = std::time::Duration::from_millis(500);
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn format_rate(bytes: u64, elapsed: std::time::Duration) -> String {
+    const UNITS: [&str; 4] = ["B/s", "KiB/s", "MiB/s", "GiB/s"];
+
+    let mut rate = bytes as f64 / elapsed.as_secs_f64().max(0.001);
+    let mut unit = 0;
 
-        if line.contains(&interface) {
-            next = !next;
+    while rate >= 1024.0 && unit < UNITS.len() - 1 {
+        rate /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", rate, UNITS[unit])
+}
+
+// Rate between two samples of `sample_net_bytes` taken `elapsed` apart,
+// e.g. "1.2 MiB/s down, 24.0 KiB/s up (eth0)".
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn format_net_rate(before: &NetBytes, after: &NetBytes, elapsed: std::time::Duration) -> String {
+    match (before, after) {
+        (Some((interface, rx_before, tx_before)), Some((current, rx_after, tx_after)))
+            if interface == current =>
+        {
+            format!(
+                "{} down, {} up ({})",
+                format_rate(rx_after.saturating_sub(*rx_before), elapsed),
+                format_rate(tx_after.saturating_sub(*tx_before), elapsed),
+                interface
+            )
         }
-    });
-
-    let x = final_str
-        .lock()
-        .unwrap()
-        .to_string()
-        .add(format!(" ({})", interface).as_str()); 
-        
-    x
+        _ => "Unknown".to_string(),
+    }
 }
@@ -1,354 +1,624 @@
-#[cfg(target_os = "linux")]
-use {
-    crate::helpers::file::file_open,
-    std::process::Command,
-    std::fs::File,
-    std::io::Read,
-    std::rc::Rc,
-};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::helpers::file;
+use crate::helpers::command;
+
+pub fn get_sysctl_cpu() -> Option<String> {
+    let output = command::run("sysctl", &["-n", "machdep.cpu.brand_string"])?;
+
+    Some(output.trim().to_string()).filter(|cpu| !cpu.is_empty())
+}
 
 #[cfg(target_os = "macos")]
 pub fn get_cpu() -> String {
-    use std::process::Command;
+    get_sysctl_cpu().unwrap_or_else(|| "Unknown CPU".to_string())
+}
 
-    let output = Command::new("sysctl")
-        .arg("-n")
-        .arg("machdep.cpu.brand_string")
-        .output();
+pub fn get_wmic_cpu() -> Option<String> {
+    let output = command::run("wmic", &["cpu", "get", "name"])?;
 
-    if let Ok(output) = output {
-        String::from_utf8_lossy(&output.stdout).trim().to_string()
-    } else {
-        "Unknown CPU".to_string()
-    }
+    command::wmic_rows(&output).pop()
 }
 
 #[cfg(target_os = "windows")]
 pub fn get_cpu() -> String {
-    use std::process::Command;
-
-    let mut cpu = String::new();
-
-    let output = Command::new("wmic")
-        .args(&["cpu", "get", "name"])
-        .output()
-        .expect("Failed to execute process");
+    get_wmic_cpu().unwrap_or_else(|| "Unknown".to_string())
+}
 
-    let output = String::from_utf8_lossy(&output.stdout);
+// wmic sorts the columns by name: "FreePhysicalMemory TotalVisibleMemorySize".
+pub fn get_wmic_ram_used() -> Option<String> {
+    let output = command::run("wmic", &["OS", "get", "FreePhysicalMemory,TotalVisibleMemorySize"])?;
+    let row = command::wmic_rows(&output).pop()?;
 
-    for line in output.lines() {
-        if !line.contains("Name") && line.trim().len() > 0 {
-            cpu = line.to_string();
-        }
-    }
+    let mut split = row.split_whitespace().map(|kbs| kbs.parse::<u64>().ok());
+    let free = split.next()??;
+    let total = split.next()??;
 
-    cpu
+    Some(format!("{}MB / {}MB", total.saturating_sub(free) / 1024, total / 1024))
 }
 
 #[cfg(target_os = "windows")]
 pub fn get_ram_used() -> String {
-    use std::process::Command;
-
-    let mut ram_used = String::new();
-    let mut ram_total = String::new();
+    get_wmic_ram_used().unwrap_or_else(|| "Unknown".to_string())
+}
 
-    let output = Command::new("wmic")
-        .args(&["OS", "get", "FreePhysicalMemory,TotalVisibleMemorySize"])
-        .output()
-        .expect("Failed to execute process");
+// Active and wired pages count as used, like Activity Monitor does.
+pub fn get_vm_stat_ram_used() -> Option<String> {
+    let total = command::run("sysctl", &["-n", "hw.memsize"])?
+        .trim()
+        .parse::<f64>()
+        .ok()?;
 
-    let output = String::from_utf8_lossy(&output.stdout);
+    let vm_stat = command::run("vm_stat", &[])?;
 
-    for line in output.lines() {
-        if line.contains("Memory") || line.trim().len() == 0 {continue};
+    // "Mach Virtual Memory Statistics: (page size of 16384 bytes)"
+    let page_size = vm_stat.lines()
+        .next()
+        .and_then(|line| line.split("page size of ").nth(1))
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|size| size.parse::<f64>().ok())
+        .unwrap_or(4096.0);
 
-        let mut split = line.split_whitespace();
+    let used_pages = vm_stat.lines()
+        .filter(|line| line.contains("Pages active") || line.contains("Pages wired down"))
+        .filter_map(|line| line.split(':').nth(1))
+        .filter_map(|pages| pages.trim().trim_end_matches('.').parse::<f64>().ok())
+        .sum::<f64>();
 
-        ram_used = split.next().unwrap().to_string();
-        ram_total = split.next().unwrap().to_string();
-    }
+    let gib = |bytes: f64| bytes / (1024.0 * 1024.0 * 1024.0);
 
-    format!(
-        "{}MB / {}MB",
-        (ram_total.parse::<u64>().unwrap() - ram_used.parse::<u64>().unwrap()) / 1024,
-        ram_total.parse::<u64>().unwrap() / 1024
-    )
+    Some(format!("{:.1} GiB / {:.1} GiB", gib(used_pages * page_size), gib(total)))
 }
 
 #[cfg(target_os = "macos")]
 pub fn get_ram_used() -> String {
-    use std::process::Command;
-
-    // Get total memory
-    let total_output = Command::new("sysctl")
-        .arg("-n")
-        .arg("hw.memsize")
-        .output();
-
-    // Get vm_stat output for active memory
-    let vm_output = Command::new("vm_stat")
-        .output();
-
-    if let (Ok(total), Ok(vm)) = (total_output, vm_output) {
-        let total_mem_bytes: f64 = String::from_utf8_lossy(&total.stdout)
-            .trim()
-            .parse::<f64>()
-            .unwrap_or(0.0);
-
-        let vm_output_str = String::from_utf8_lossy(&vm.stdout);
-        let mut used_pages = 0.0;
-        for line in vm_output_str.lines() {
-            if line.contains("Pages active") || line.contains("Pages wired down") {
-                if let Some(num) = line.split(':').nth(1) {
-                    used_pages += num.trim().replace(".", "").parse::<f64>().unwrap_or(0.0);
-                }
-            }
-        }
+    get_vm_stat_ram_used().unwrap_or_else(|| "Unknown RAM usage".to_string())
+}
 
-        let page_size = 4096.0; // default macOS page size
-        let used_mem = used_pages * page_size;
+pub fn get_wmic_product() -> Option<String> {
+    let output = command::run("wmic", &["OS", "get", "Caption"])?;
 
-        format!(
-            "{:.1} GiB / {:.1} GiB",
-            used_mem / (1024.0 * 1024.0 * 1024.0),
-            total_mem_bytes / (1024.0 * 1024.0 * 1024.0)
-        )
-    } else {
-        "Unknown RAM usage".to_string()
-    }
+    command::wmic_rows(&output).pop()
 }
 
-
 #[cfg(target_os = "windows")]
 pub fn get_kernel() -> String {
-    use std::process::Command;
-
-    let mut kernel = String::new();
-
-    let output = Command::new("wmic")
-        .args(&["OS", "get", "Caption"])
-        .output()
-        .expect("Failed to execute process");
+    get_wmic_product().unwrap_or_else(|| "Unknown".to_string())
+}
 
-    let output = String::from_utf8_lossy(&output.stdout);
+// wmic sorts the columns by name: "Caption FreeSpace Size". Drives without
+// a medium only have a caption and are skipped.
+pub fn get_wmic_disk_usage() -> Option<String> {
+    let output = command::run("wmic", &["logicaldisk", "get", "size,freespace,caption"])?;
+    let gb = |bytes: u64| bytes / 1024 / 1024 / 1024;
 
-    for line in output.lines() {
-        if !line.contains("Caption") && line.trim().len() > 0 {
-            kernel = line.to_string();
-        }
+    let disks = command::wmic_rows(&output)
+        .iter()
+        .filter_map(|row| {
+            let mut split = row.split_whitespace();
+            let name = split.next()?;
+            let free = split.next()?.parse::<u64>().ok()?;
+            let size = split.next()?.parse::<u64>().ok()?;
+
+            Some(format!("{} {}GB / {}GB", name, gb(size.saturating_sub(free)), gb(size)))
+        })
+        .collect::<Vec<String>>();
+
+    match disks.is_empty() {
+        true => None,
+        false => Some(disks.join(", ")),
     }
-
-    kernel
 }
 
 #[cfg(target_os = "windows")]
 pub fn get_disk_usage() -> String {
-    use std::process::Command;
+    get_wmic_disk_usage().unwrap_or_else(|| "Unknown".to_string())
+}
 
-    let mut disk = String::new();
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_arch() -> String {
+    command::run("uname", &["-m"])
+        .map(|arch| arch.trim().to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
 
-    let output = Command::new("wmic")
-        .args(&["logicaldisk", "get", "size,freespace,caption"])
-        .output()
-        .expect("Failed to execute process");
+#[cfg(target_os = "macos")]
+pub fn get_arch() -> String {
+    std::env::consts::ARCH.to_string()
+}
+
+pub fn get_wmic_arch() -> Option<String> {
+    let output = command::run("wmic", &["path", "Win32_OperatingSystem", "get", "OSArchitecture"])?;
 
-    let output = String::from_utf8_lossy(&output.stdout);
+    command::wmic_rows(&output).pop()
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_arch() -> String {
+    get_wmic_arch().unwrap_or_else(|| "Unknown".to_string())
+}
 
-    for line in output.lines() {
-        if line.contains("Caption") || line.trim().len() == 0 {continue};
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const CPU_IMPLEMENTERS: [(&str, &str); 11] = [
+    ("0x41", "ARM"),
+    ("0x42", "Broadcom"),
+    ("0x43", "Cavium"),
+    ("0x48", "HiSilicon"),
+    ("0x4e", "NVIDIA"),
+    ("0x50", "APM"),
+    ("0x51", "Qualcomm"),
+    ("0x53", "Samsung"),
+    ("0x61", "Apple"),
+    ("0x6d", "Microsoft"),
+    ("0xc0", "Ampere"),
+];
 
-        let mut split = line.split_whitespace();
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const CPU_PARTS: [(&str, &str); 27] = [
+    ("0xb76", "ARM1176"),
+    ("0xc07", "Cortex-A7"),
+    ("0xc0f", "Cortex-A15"),
+    ("0xd03", "Cortex-A53"),
+    ("0xd04", "Cortex-A35"),
+    ("0xd05", "Cortex-A55"),
+    ("0xd07", "Cortex-A57"),
+    ("0xd08", "Cortex-A72"),
+    ("0xd09", "Cortex-A73"),
+    ("0xd0a", "Cortex-A75"),
+    ("0xd0b", "Cortex-A76"),
+    ("0xd0c", "Neoverse-N1"),
+    ("0xd0d", "Cortex-A77"),
+    ("0xd40", "Neoverse-V1"),
+    ("0xd41", "Cortex-A78"),
+    ("0xd44", "Cortex-X1"),
+    ("0xd46", "Cortex-A510"),
+    ("0xd47", "Cortex-A710"),
+    ("0xd48", "Cortex-X2"),
+    ("0xd49", "Neoverse-N2"),
+    ("0xd4b", "Cortex-A78C"),
+    ("0xd4d", "Cortex-A715"),
+    ("0xd4e", "Cortex-X3"),
+    ("0xd4f", "Neoverse-V2"),
+    ("0xd80", "Cortex-A520"),
+    ("0xd81", "Cortex-A720"),
+    ("0xd82", "Cortex-X4"),
+];
+
+// hwmon drivers that report the CPU package temperature as temp1.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const CPU_SENSORS: [&str; 6] = [
+    "coretemp", "k10temp", "zenpower", "cpu_thermal", "soc_thermal", "cpu-thermal",
+];
 
-        let name = split.next().unwrap().trim().to_string();
-        let free = split.next().unwrap().trim().to_string();
-        let size = split.next().unwrap().trim().to_string();
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn cpuinfo_value(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(':')?;
 
-        disk.push_str(&format!(
-            "{} {}GB / {}GB",
-            name,
-            (
-                size.parse::<u64>().unwrap() - 
-                free.parse::<u64>().unwrap()
-            ) 
-            / 1024 / 1024 / 1024,
-
-            size.parse::<u64>().unwrap() 
-            / 1024 / 1024 / 1024
-        ));
-    }
+    Some((key.trim(), value.trim()))
+}
 
-    disk
+// Parses kernel cpu lists such as "0-11,16,18-19".
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parse_cpu_list(list: &str) -> Vec<u32> {
+    list.trim()
+        .split(',')
+        .filter_map(|range| {
+            let mut split = range.split('-');
+            let start = split.next()?.trim().parse::<u32>().ok()?;
+            let end = split.next().map_or(Some(start), |end| end.trim().parse::<u32>().ok())?;
+
+            Some(start..=end)
+        })
+        .flatten()
+        .collect()
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
-pub fn get_arch() -> String {
-    Command::new("uname")
-        .arg("-m")
-        .output()
-        .expect("Failed to execute process")
-        .stdout
-        .iter()
-        .map(|&c| c as char)
-        .collect::<String>()
-        .trim().to_string()
+fn read_sys_cpu(cpu: u32, file: &str) -> Option<String> {
+    file::read(&format!("/sys/devices/system/cpu/cpu{}/{}", cpu, file))
+        .map(|value| value.trim().to_string())
 }
 
-#[cfg(target_os = "macos")]
-pub fn get_arch() -> String {
-    std::env::consts::ARCH.to_string()
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn count_cores(cpus: &[u32]) -> usize {
+    let mut cores = cpus.iter()
+        .map(|&cpu| {
+            (
+                read_sys_cpu(cpu, "topology/physical_package_id"),
+                read_sys_cpu(cpu, "topology/core_id"),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    cores.sort();
+    cores.dedup();
+
+    cores.len()
 }
 
-#[cfg(target_os = "windows")]
-pub fn get_arch() -> String {
-    use std::process::Command;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_cpu_model(cpuinfo: &str) -> String {
+    let find = |name: &str| {
+        cpuinfo.lines()
+            .filter_map(cpuinfo_value)
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    };
 
-    let mut uptime = String::new();
+    if let Some(model) = find("model name") {
+        return model;
+    }
 
-    let output = Command::new("wmic")
-        .args(&["path", "Win32_OperatingSystem", "get", "OSArchitecture"])
-        .output()
-        .expect("Failed to execute process");
+    // ARM kernels don't report a model name, only implementer/part codes
+    // for every core, possibly with several core types (big.LITTLE).
+    let mut implementer = None;
+    let mut parts: Vec<String> = Vec::new();
+
+    for (key, value) in cpuinfo.lines().filter_map(cpuinfo_value) {
+        if key == "CPU implementer" && implementer.is_none() {
+            implementer = CPU_IMPLEMENTERS.iter()
+                .find(|(code, _)| code.eq_ignore_ascii_case(value))
+                .map(|(_, name)| name.to_string());
+        }
 
-    let output = String::from_utf8_lossy(&output.stdout);
+        if key == "CPU part" {
+            let part = CPU_PARTS.iter()
+                .find(|(code, _)| code.eq_ignore_ascii_case(value))
+                .map_or(value.to_string(), |(_, name)| name.to_string());
 
-    for line in output.lines() {
-        if !line.contains("OSArchitecture") && line.trim().len() > 0 {
-            uptime = line.trim().to_string()
+            if !parts.contains(&part) {
+                parts.push(part);
+            }
         }
-    };
+    }
 
-    uptime
+    match (implementer, parts.is_empty()) {
+        (Some(implementer), false) => format!("{} {}", implementer, parts.join(" + ")),
+        (Some(implementer), true) => implementer,
+        _ => find("Hardware").or(find("Processor")).unwrap_or_else(|| "Unknown".to_string()),
+    }
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
-pub fn get_cpu() -> String {
-    let mut cpu: Rc<String> = Rc::new(String::new());
-    let mut temp_buf: String = String::new();
-
-    let mut file = File::open("/proc/cpuinfo").unwrap();
-    file.read_to_string(&mut temp_buf).unwrap();
-
-    let lines: &Vec<&str> = &temp_buf.lines().collect();
-
-    lines.into_iter().for_each(|line| {
-        if line.contains("model name") {
-            cpu = Rc::new(
-                line.split(":")
-                    .collect::<Vec<&str>>()[1].to_string()
-                    .replace("\t", "")
-            );
-            cpu = Rc::new(cpu.replacen(" ", "", 1));
+fn get_cpu_topology(cpuinfo: &str) -> String {
+    let mut threads = 0;
+    let mut cores: Vec<(String, String)> = Vec::new();
+    let mut physical_id = String::new();
+
+    for (key, value) in cpuinfo.lines().filter_map(cpuinfo_value) {
+        match key {
+            "processor" => threads += 1,
+            "physical id" => physical_id = value.to_string(),
+            "core id" => cores.push((physical_id.clone(), value.to_string())),
+            _ => ()
         }
-    });
+    }
+
+    cores.sort();
+    cores.dedup();
+
+    let threads_list = (0..threads).collect::<Vec<u32>>();
+    let core_count = match cores.len() {
+        0 => count_cores(&threads_list),
+        len => len,
+    };
 
-    cpu.to_string()
+    // Intel hybrid CPUs split their cores between two PMUs.
+    let performance = file::read("/sys/devices/cpu_core/cpus");
+    let efficiency = file::read("/sys/devices/cpu_atom/cpus");
+
+    match (performance, efficiency) {
+        (Some(performance), Some(efficiency)) => format!(
+            "{}P+{}E/{}T",
+            count_cores(&parse_cpu_list(&performance)),
+            count_cores(&parse_cpu_list(&efficiency)),
+            threads
+        ),
+        _ => format!("{}C/{}T", core_count, threads),
+    }
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
-pub fn get_ram_used() -> String {
-    let temp_buf: String = file_open("/proc/meminfo");
+fn get_cpu_frequency() -> Option<String> {
+    let online = file::read("/sys/devices/system/cpu/online")?;
+    let cpus = parse_cpu_list(&online);
+
+    let read_khz = |file: &str| {
+        cpus.iter()
+            .filter_map(|&cpu| read_sys_cpu(cpu, file)?.parse::<u64>().ok())
+            .collect::<Vec<u64>>()
+    };
 
-    let lines: &Vec<&str> = &temp_buf.lines().collect();
+    let max = read_khz("cpufreq/cpuinfo_max_freq").into_iter().max()?;
+    let current = read_khz("cpufreq/scaling_cur_freq");
 
-    let mut total: u128 = 0;
-    let mut available: u128 = 0;
+    let ghz = |khz: u64| khz as f64 / 1_000_000.0;
 
-    lines.into_iter().for_each(|line| {
-        if line.contains("MemTotal") {
-            total = eval_ram(line.to_string());
-        } else if line.contains("MemAvailable") {
-            available = eval_ram(line.to_string());
-        }
-    });
+    match current.iter().max() {
+        Some(&current) => Some(format!("{:.2}/{:.2} GHz", ghz(current), ghz(max))),
+        None => Some(format!("{:.2} GHz", ghz(max))),
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_cpu() -> String {
+    let cpuinfo = file::file_open("/proc/cpuinfo");
+
+    let mut cpu = format!("{} ({})", get_cpu_model(&cpuinfo), get_cpu_topology(&cpuinfo));
 
-    format!(
-        "{}M / {}M",
-        total - available,
-        total
-    )
+    if let Some(frequency) = get_cpu_frequency() {
+        cpu.push_str(&format!(" @ {}", frequency));
+    }
+
+    cpu
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
-fn eval_ram(line: String) -> u128 {
-    let kbs: u128 = line.split(":")
-        .collect::<Vec<&str>>()[1].to_string()
-        .replace("\t", "")
-        .replace("kB", "")
-        .replace(" ", "")
-        .parse::<u128>()
-        .unwrap();
+pub fn get_cpu_temperature() -> String {
+    let sensor = file::read_dir("/sys/class/hwmon")
+        .into_iter()
+        .find(|path| {
+            file::read(&format!("{}/name", path))
+                .map(|name| CPU_SENSORS.contains(&name.trim()))
+                .unwrap_or(false)
+        });
+
+    sensor.and_then(|path| file::read(&format!("{}/temp1_input", path)))
+        .and_then(|temp| temp.trim().parse::<f64>().ok())
+        .map(|millidegrees| format!("{:.1}°C", millidegrees / 1000.0))
+        .unwrap_or_else(|| "Unknown".to_string())
+}
 
-    kbs / 1000
+// The CPU line with the temperature appended, when a sensor is found.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_cpu_with_temperature() -> String {
+    match get_cpu_temperature() {
+        temperature if temperature == "Unknown" => get_cpu(),
+        temperature => format!("{}, {}", get_cpu(), temperature),
+    }
 }
 
-#[cfg(target_os = "windows")]
-pub fn get_gpu() -> String {
-    use std::process::Command;
+// Placeholder strings firmware vendors leave in unset DMI fields.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const DMI_PLACEHOLDERS: [&str; 12] = [
+    "to be filled by o.e.m.",
+    "to be filled by oem",
+    "default string",
+    "system product name",
+    "system manufacturer",
+    "system version",
+    "not applicable",
+    "not specified",
+    "type1productconfigid",
+    "0123456789",
+    "none",
+    "x.x",
+];
 
-    let mut gpu = String::new();
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn read_dmi(field: &str) -> Option<String> {
+    let value = file::read(&format!("/sys/class/dmi/id/{}", field))?;
+    let value = value.trim();
 
-    let output = Command::new("wmic")
-        .args(&["path", "win32_VideoController", "get", "Name"])
-        .output()
-        .expect("Failed to execute process");
+    if value.is_empty() || DMI_PLACEHOLDERS.contains(&value.to_lowercase().as_str()) {
+        return None;
+    }
 
-    let output = String::from_utf8_lossy(&output.stdout);
+    Some(value.to_string())
+}
 
-    for line in output.lines() {
-        if !line.contains("Name") && line.trim().len() > 0 {
-            gpu = line.to_string();
-        }
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn join_dmi(fields: &[&str]) -> Option<String> {
+    let values = fields.iter()
+        .filter_map(|field| read_dmi(field))
+        .collect::<Vec<String>>();
+
+    if values.is_empty() {
+        return None;
     }
 
-    gpu
+    Some(values.join(" "))
 }
 
 #[cfg(any(target_os = "linux", target_os = "android"))]
-pub fn get_gpu() -> String {
-    use std::process::Command;
+pub fn get_host_model() -> String {
+    // Device tree boards (Raspberry Pi etc.) null-terminate the model.
+    let device_tree = file::read("/proc/device-tree/model")
+        .map(|model| model.trim_end_matches('\0').trim().to_string())
+        .filter(|model| !model.is_empty());
+
+    device_tree
+        .or_else(|| join_dmi(&["sys_vendor", "product_name", "product_version"]))
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_bios() -> String {
+    match (read_dmi("bios_version"), read_dmi("bios_date")) {
+        (Some(version), Some(date)) => format!("{} ({})", version, date),
+        (Some(version), None) => version,
+        (None, Some(date)) => date,
+        (None, None) => "Unknown".to_string(),
+    }
+}
 
-    let mut gpu = String::new();
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_motherboard() -> String {
+    join_dmi(&["board_vendor", "board_name"]).unwrap_or_else(|| "Unknown".to_string())
+}
 
-    let output = Command::new("lspci")
-        .arg("-v")
-        .output()
-        .expect("Failed to execute lspci command");
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_load() -> String {
+    let loadavg = file::file_open("/proc/loadavg");
+    let averages = loadavg.split_whitespace().take(3).collect::<Vec<&str>>();
 
-    let output = String::from_utf8_lossy(&output.stdout);
+    if averages.len() < 3 {
+        return "Unknown".to_string();
+    }
 
-    for line in output.lines() {
-        if line.contains("VGA") || line.contains("3D") {
-            if let Some(pos) = line.find(": ") {
-                gpu = line[pos + 2..].trim().to_string();
-                break;
+    averages.join(", ")
+}
+
+// Charge and state of every battery, e.g. "85% (Discharging)".
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_battery() -> String {
+    let batteries = file::read_dir("/sys/class/power_supply")
+        .into_iter()
+        .filter(|path| {
+            file::read(&format!("{}/type", path))
+                .map(|kind| kind.trim() == "Battery")
+                .unwrap_or(false)
+        })
+        .filter_map(|path| {
+            let capacity = file::read(&format!("{}/capacity", path))?;
+
+            match file::read(&format!("{}/status", path)) {
+                Some(status) => Some(format!("{}% ({})", capacity.trim(), status.trim())),
+                None => Some(format!("{}%", capacity.trim())),
             }
-        }
+        })
+        .collect::<Vec<String>>();
+
+    if batteries.is_empty() {
+        return "Unknown".to_string();
     }
 
-    gpu
+    batteries.join(", ")
 }
 
-#[cfg(target_os = "macos")]
-pub fn get_gpu() -> String {
-    use std::process::Command;
+// (busy, total) jiffies for the "cpu" line followed by every core.
+pub type CpuTimes = Vec<(u64, u64)>;
 
-    let mut gpu = String::new();
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn sample_cpu_times() -> CpuTimes {
+    let stat = file::file_open("/proc/stat");
+
+    stat.lines()
+        .filter(|line| line.starts_with("cpu"))
+        .map(|line| {
+            let fields = line.split_whitespace()
+                .skip(1)
+                .take(8)
+                .filter_map(|field| field.parse::<u64>().ok())
+                .collect::<Vec<u64>>();
+
+            // idle + iowait
+            let idle = fields.get(3).unwrap_or(&0) + fields.get(4).unwrap_or(&0);
+            let total = fields.iter().sum::<u64>();
+
+            (total - idle, total)
+        })
+        .collect()
+}
 
-    let output = Command::new("system_profiler")
-        .args(&["SPDisplaysDataType"])
-        .output()
-        .expect("Failed to execute system_profiler command");
+// Time `sample_cpu_times` needs between samples for the jiffies to add up.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub const CPU_SAMPLE_TIME: std::time::Duration = std::time::Duration::from_millis(200);
 
-    let output = String::from_utf8_lossy(&output.stdout);
+// Usage between two samples of `sample_cpu_times`, followed by a sparkline
+// of every core when `per_core` is set.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn format_cpu_usage(before: &[(u64, u64)], after: &[(u64, u64)], per_core: bool) -> String {
+    const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let usage = before.iter()
+        .zip(after.iter())
+        .map(|((busy_before, total_before), (busy_after, total_after))| {
+            let total = total_after.saturating_sub(*total_before);
 
-    for line in output.lines() {
-        if line.contains("Chipset Model:") {
-            if let Some(pos) = line.find(": ") {
-                gpu = line[pos + 2..].trim().to_string();
-                break;
+            if total == 0 {
+                return 0.0;
             }
-        }
+
+            busy_after.saturating_sub(*busy_before) as f64 / total as f64
+        })
+        .collect::<Vec<f64>>();
+
+    let Some(total) = usage.first() else {
+        return "Unknown".to_string();
+    };
+
+    let mut final_str = format!("{:.0}%", total * 100.0);
+
+    if per_core && usage.len() > 2 {
+        let sparkline = usage[1..].iter()
+            .map(|core| SPARKS[((core * 7.0).round() as usize).min(7)])
+            .collect::<String>();
+
+        final_str.push_str(&format!(" {}", sparkline));
     }
 
-    gpu
-}
\ No newline at end of file
+    final_str
+}
+
+// "MemTotal:       16316412 kB" as ("MemTotal", 16316412).
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn meminfo_value(line: &str) -> Option<(&str, u64)> {
+    let (key, value) = cpuinfo_value(line)?;
+
+    Some((key, value.trim_end_matches("kB").trim().parse().ok()?))
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_ram_used() -> String {
+    let meminfo = file::file_open("/proc/meminfo");
+    let values = meminfo.lines()
+        .filter_map(meminfo_value)
+        .collect::<std::collections::HashMap<&str, u64>>();
+
+    let Some(total) = values.get("MemTotal").copied() else {
+        return "Unknown".to_string();
+    };
+
+    // Kernels before 3.14 have no MemAvailable, free memory and caches
+    // come close to it.
+    let available = values.get("MemAvailable").copied().unwrap_or_else(|| {
+        ["MemFree", "Buffers", "Cached"].iter().filter_map(|key| values.get(key)).sum()
+    });
+
+    format!("{}M / {}M", (total / 1000).saturating_sub(available / 1000), total / 1000)
+}
+
+pub fn get_wmic_gpus() -> Vec<String> {
+    command::run("wmic", &["path", "win32_VideoController", "get", "Name"])
+        .map(|output| command::wmic_rows(&output))
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_gpus() -> Vec<String> {
+    get_wmic_gpus()
+}
+
+// "00:02.0 VGA compatible controller: Intel Corporation UHD Graphics 620 (rev 07)"
+pub fn get_lspci_gpus() -> Vec<String> {
+    let output = command::run("lspci", &[]).unwrap_or_default();
+
+    output.lines()
+        .filter(|line| {
+            line.contains("VGA compatible controller")
+                || line.contains("3D controller")
+                || line.contains("Display controller")
+        })
+        .filter_map(|line| line.split_once(": "))
+        .map(|(_, gpu)| gpu.split(" (rev").next().unwrap_or(gpu).trim().to_string())
+        .collect()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_gpus() -> Vec<String> {
+    get_lspci_gpus()
+}
+
+pub fn get_system_profiler_gpus() -> Vec<String> {
+    command::run("system_profiler", &["SPDisplaysDataType"])
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("Chipset Model:"))
+        .map(|gpu| gpu.trim().to_string())
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+pub fn get_gpus() -> Vec<String> {
+    get_system_profiler_gpus()
+}
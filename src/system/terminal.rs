@@ -0,0 +1,226 @@
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use {
+    crate::helpers::{command, file, process},
+    crate::system::host::{SHELLS, WRAPPERS},
+};
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn pretty_name(name: &str) -> String {
+    match name {
+        "kitty" => "kitty",
+        "alacritty" => "Alacritty",
+        "foot" | "footclient" => "foot",
+        "wezterm" | "wezterm-gui" => "WezTerm",
+        "konsole" => "Konsole",
+        "xterm" => "XTerm",
+        "urxvt" | "rxvt" => "rxvt-unicode",
+        "st" => "st",
+        "tilix" => "Tilix",
+        "terminator" => "Terminator",
+        "xfce4-terminal" => "Xfce Terminal",
+        "sshd" => "SSH",
+        name if name.starts_with("gnome-terminal") => "GNOME Terminal",
+        name if name.starts_with("tmux") => "tmux",
+        name if name.eq_ignore_ascii_case("screen") => "screen",
+        name => name,
+    }.to_string()
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn find_terminal() -> Option<String> {
+    process::get_ancestors()
+        .into_iter()
+        .filter_map(process::get_name)
+        .find(|name| {
+            !SHELLS.contains(&name.as_str()) && !WRAPPERS.contains(&name.as_str())
+        })
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_terminal() -> String {
+    if let Some(name) = find_terminal() {
+        return pretty_name(&name);
+    }
+
+    std::env::var("TERM_PROGRAM")
+        .or_else(|_| std::env::var("TERM"))
+        .unwrap_or_else(|_| "Unknown".to_string())
+}
+
+#[cfg(target_os = "macos")]
+pub fn get_terminal() -> String {
+    match std::env::var("TERM_PROGRAM") {
+        Ok(term) if term == "Apple_Terminal" => "Terminal.app".to_string(),
+        Ok(term) if term == "iTerm.app" => "iTerm2".to_string(),
+        Ok(term) => term,
+        Err(_) => "Unknown".to_string(),
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_terminal() -> String {
+    if std::env::var("WT_SESSION").is_ok() {
+        return "Windows Terminal".to_string();
+    }
+
+    std::env::var("TERM_PROGRAM").unwrap_or_else(|_| "Windows Console".to_string())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn config_dir() -> String {
+    std::env::var("XDG_CONFIG_HOME").unwrap_or_else(|_| {
+        format!("{}/.config", std::env::var("HOME").unwrap_or_default())
+    })
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn read_config(paths: &[String]) -> Option<String> {
+    paths.iter().find_map(|path| file::read(path))
+}
+
+// Returns the first double or single quoted string found in `text`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn first_quoted(text: &str) -> Option<String> {
+    let start = text.find(['"', '\''])?;
+    let quote = text[start..].chars().next()?;
+    let rest = &text[start + 1..];
+
+    Some(rest[..rest.find(quote)?].to_string())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn join_font(family: Option<String>, size: Option<String>) -> Option<String> {
+    match (family, size) {
+        (Some(family), Some(size)) => Some(format!("{} {}", family, size)),
+        (Some(family), None) => Some(family),
+        _ => None,
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_kitty_font() -> Option<String> {
+    let config = read_config(&[format!("{}/kitty/kitty.conf", config_dir())])?;
+
+    let mut family = None;
+    let mut size = None;
+
+    for line in config.lines().map(str::trim) {
+        let mut split = line.splitn(2, char::is_whitespace);
+
+        match (split.next(), split.next()) {
+            (Some("font_family"), Some(value)) => family = Some(value.trim().to_string()),
+            (Some("font_size"), Some(value)) => size = Some(value.trim().to_string()),
+            _ => ()
+        }
+    }
+
+    // kitty falls back to the system monospace font at 11pt.
+    join_font(family.or(Some("monospace".to_string())), size.or(Some("11".to_string())))
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_alacritty_font() -> Option<String> {
+    let dir = config_dir();
+    let config = read_config(&[
+        format!("{}/alacritty/alacritty.toml", dir),
+        format!("{}/alacritty/alacritty.yml", dir),
+        format!("{}/alacritty.toml", dir),
+        format!("{}/alacritty.yml", dir),
+    ])?;
+
+    let mut section = String::new();
+    let mut family = None;
+    let mut size = None;
+
+    for line in config.lines().map(str::trim) {
+        if line.starts_with('[') {
+            section = line.trim_matches(|c| c == '[' || c == ']').to_string();
+            continue;
+        }
+
+        // TOML keeps the font under [font] / [font.normal], the legacy YAML
+        // format has no sections so any "family"/"size" key is accepted.
+        if !section.is_empty() && !section.starts_with("font") {
+            continue;
+        }
+
+        let value = line.split_once(['=', ':']).map(|(_, value)| value.trim());
+
+        if line.contains("family") && family.is_none() {
+            family = first_quoted(line).or(value.map(str::to_string));
+        } else if line.starts_with("size") && size.is_none() {
+            size = value.map(str::to_string);
+        }
+    }
+
+    join_font(family, size)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_foot_font() -> Option<String> {
+    let config = read_config(&[format!("{}/foot/foot.ini", config_dir())])
+        .unwrap_or_default();
+
+    let font = config.lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix("font="))
+        .unwrap_or("monospace:size=8");
+
+    // Only the primary font is shown, fallbacks are comma separated.
+    let primary = font.split(',').next()?.trim();
+    let mut split = primary.split(':');
+    let family = split.next().map(str::to_string);
+    let size = split.find_map(|attr| attr.strip_prefix("size=")).map(str::to_string);
+
+    join_font(family, size)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_wezterm_font() -> Option<String> {
+    let config = read_config(&[
+        format!("{}/wezterm/wezterm.lua", config_dir()),
+        format!("{}/.wezterm.lua", std::env::var("HOME").unwrap_or_default()),
+    ])?;
+
+    let mut family = None;
+    let mut size = None;
+
+    for line in config.lines().map(str::trim) {
+        if line.starts_with("--") {
+            continue;
+        }
+
+        if let Some(pos) = line.find("wezterm.font") {
+            family = family.or(first_quoted(&line[pos..]));
+        } else if line.contains("font_size") {
+            size = line.split('=')
+                .nth(1)
+                .map(|value| value.trim().trim_end_matches(',').to_string());
+        }
+    }
+
+    join_font(family, size)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_gnome_terminal_font() -> Option<String> {
+    let output = command::run("gsettings", &[
+        "get", "org.gnome.desktop.interface", "monospace-font-name"
+    ])?;
+
+    first_quoted(&output)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_terminal_font() -> String {
+    let font = match find_terminal().map(|name| pretty_name(&name)).as_deref() {
+        Some("kitty") => get_kitty_font(),
+        Some("Alacritty") => get_alacritty_font(),
+        Some("foot") => get_foot_font(),
+        Some("WezTerm") => get_wezterm_font(),
+        Some("GNOME Terminal") => get_gnome_terminal_font(),
+        _ => None
+    };
+
+    font.unwrap_or_else(|| "Unknown".to_string())
+}
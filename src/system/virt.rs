@@ -0,0 +1,104 @@
+#[cfg(any(target_os = "linux", target_os = "android"))]
+use crate::{helpers::file, system::specs::read_dmi};
+
+// Substrings of DMI vendor/product strings and the hypervisor they belong to.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const HYPERVISORS: [(&str, &str); 14] = [
+    ("kvm", "KVM"),
+    ("qemu", "QEMU"),
+    ("vmware", "VMware"),
+    ("virtualbox", "VirtualBox"),
+    ("innotek", "VirtualBox"),
+    ("xen", "Xen"),
+    ("amazon ec2", "Amazon EC2"),
+    ("google compute engine", "Google Compute Engine"),
+    ("parallels", "Parallels"),
+    ("bochs", "Bochs"),
+    ("bhyve", "bhyve"),
+    ("openstack", "OpenStack"),
+    ("apple virtualization", "Apple Virtualization"),
+    ("cloud hypervisor", "Cloud Hypervisor"),
+];
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_hypervisor() -> Option<String> {
+    let osrelease = file::file_open("/proc/sys/kernel/osrelease");
+
+    if osrelease.to_lowercase().contains("microsoft") {
+        return Some("WSL".to_string());
+    }
+
+    let dmi = ["sys_vendor", "product_name", "bios_vendor", "board_vendor"]
+        .iter()
+        .filter_map(|field| read_dmi(field))
+        .collect::<Vec<String>>()
+        .join(" ")
+        .to_lowercase();
+
+    if dmi.contains("microsoft") && dmi.contains("virtual") {
+        return Some("Hyper-V".to_string());
+    }
+
+    if let Some((_, name)) = HYPERVISORS.iter().find(|(key, _)| dmi.contains(key)) {
+        return Some(name.to_string());
+    }
+
+    if let Some(hypervisor) = file::read("/sys/hypervisor/type") {
+        return Some(hypervisor.trim().to_string());
+    }
+
+    // CPUID leaf 1 ECX bit 31, exposed by the kernel as the "hypervisor" flag.
+    let cpuinfo = file::file_open("/proc/cpuinfo");
+    let is_virtual = cpuinfo.lines()
+        .filter(|line| line.starts_with("flags"))
+        .any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"));
+
+    if is_virtual {
+        return Some("Virtual machine".to_string());
+    }
+
+    None
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn get_container() -> Option<String> {
+    let exists = file::exists;
+
+    if exists("/.dockerenv") {
+        return Some("Docker".to_string());
+    }
+
+    if exists("/run/.containerenv") {
+        return Some("Podman".to_string());
+    }
+
+    // Set by systemd-nspawn, LXC, Podman and most other runtimes for PID 1.
+    if let Ok(container) = std::env::var("container") {
+        return Some(match container.as_str() {
+            "systemd-nspawn" => "systemd-nspawn".to_string(),
+            "lxc" | "lxc-libvirt" => "LXC".to_string(),
+            "podman" => "Podman".to_string(),
+            "docker" => "Docker".to_string(),
+            "oci" => "OCI".to_string(),
+            other => other.to_string(),
+        });
+    }
+
+    let cgroup = file::file_open("/proc/1/cgroup");
+
+    [("kubepods", "Kubernetes"), ("docker", "Docker"), ("lxc", "LXC"), ("libpod", "Podman")]
+        .iter()
+        .find(|(key, _)| cgroup.contains(key))
+        .map(|(_, name)| name.to_string())
+}
+
+// "Unknown" on bare metal, which hides the line.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn get_virtualization() -> String {
+    match (get_hypervisor(), get_container()) {
+        (Some(hypervisor), Some(container)) => format!("{} / {}", hypervisor, container),
+        (Some(hypervisor), None) => hypervisor,
+        (None, Some(container)) => container,
+        (None, None) => "Unknown".to_string(),
+    }
+}
@@ -0,0 +1,136 @@
+use boykisserfetch::helpers::art::{self, Art, Segment, FRAME_DELAY};
+
+fn segment(slot: usize, text: &str) -> Segment {
+    Segment { slot, text: text.to_string() }
+}
+
+#[test]
+fn plain_text() {
+    let art = Art::parse("⠀⣰⠛\n⡼⡁⢰⣋");
+
+    assert_eq!(art.height(), 2);
+    assert_eq!(art.width(), 4);
+    assert_eq!(art.frames[0].lines[0], [segment(0, "⠀⣰⠛")]);
+    assert_eq!(art.palette("blue", false), ["blue"; 6]);
+}
+
+#[test]
+fn markers() {
+    let art = Art::parse("colors: white bright_magenta cyan\n${c1}⠀⣰${c2}⠛⠛\n⡼${c3}⢰${c9}\n\n${c1}");
+
+    assert_eq!(art.height(), 4);
+    assert_eq!(art.width(), 7);
+    assert_eq!(art.frames[0].lines[0], [segment(0, "⠀⣰"), segment(1, "⠛⠛")]);
+
+    // Slots carry over to the next line, unknown markers are kept as text.
+    assert_eq!(art.frames[0].lines[1], [segment(1, "⡼"), segment(2, "⢰${c9}")]);
+    assert_eq!(art.frames[0].lines[2], [segment(2, "")]);
+    assert_eq!(art.frames[0].lines[3], [segment(0, "")]);
+}
+
+#[test]
+fn palette() {
+    let art = Art::parse("colors: white bright_magenta not_a_color cyan\n${c1}⠀");
+
+    assert_eq!(
+        art.palette("red", false),
+        ["white", "bright_magenta", "white", "cyan", "white", "white"]
+    );
+
+    // --color only replaces the primary slot.
+    assert_eq!(
+        art.palette("red", true),
+        ["red", "bright_magenta", "red", "cyan", "red", "red"]
+    );
+}
+
+#[test]
+fn frames() {
+    let art = Art::parse("colors: white cyan\nframe: 250\n${c2}⠀⣰\n⡼\nframe:\n⠛⠛⠛");
+
+    assert!(art.is_animated());
+    assert_eq!((art.height(), art.width()), (2, 3));
+    assert_eq!(art.frames[0].delay, 250);
+    assert_eq!(art.frames[0].lines[1], [segment(1, "⡼")]);
+
+    // Frames start over in slot 1 and fall back to the default delay.
+    assert_eq!(art.frames[1].delay, FRAME_DELAY);
+    assert_eq!(art.frames[1].lines, [[segment(0, "⠛⠛⠛")]]);
+    assert!(!Art::parse("⠀⣰").is_animated());
+}
+
+#[test]
+fn mirror_and_flip() {
+    let art = Art::parse("colors: white cyan\n${c1}⠁(/\n${c2}⣇");
+
+    // Short lines are padded first so the art stays aligned on the right.
+    let mirrored = art.mirror();
+    assert_eq!(mirrored.frames[0].lines[0], [segment(0, "\\)⠈")]);
+    assert_eq!(mirrored.frames[0].lines[1], [segment(1, "  ⣸")]);
+
+    let flipped = art.flip();
+    assert_eq!(flipped.frames[0].lines[0], [segment(1, "⡏")]);
+    assert_eq!(flipped.frames[0].lines[1], [segment(0, "⡀(\\")]);
+
+    assert_eq!(art.flip().flip().frames, art.frames);
+}
+
+#[test]
+fn scale_and_crop() {
+    // Every 2x2 block of cells becomes one cell with the dots of all four.
+    let art = Art::parse("⣿⠀⠀⠀\n⣿⠀⠀█\n⠀⠀⠀⠀");
+    let scaled = art.scale(0.5);
+
+    assert_eq!((scaled.height(), scaled.width()), (2, 2));
+    assert_eq!(scaled.frames[0].lines[0], [segment(0, "⡇⢠")]);
+    assert_eq!(scaled.frames[0].lines[1], [segment(0, "⠀⠀")]);
+
+    assert_eq!(art.scale(1.0).frames, art.frames);
+    assert_eq!(art.crop(1).height(), 1);
+}
+
+#[test]
+fn fractional_scale() {
+    let art = Art::parse("⣿⠀⠀⣿\n⣿⠀⠀⣿\n⣿⠀⠀⣿\n⣿⠀⠀⣿");
+
+    // 8 dots become 6, the gap in the middle shrinks along with the bars.
+    let scaled = art.scale(0.75);
+    assert_eq!((scaled.height(), scaled.width()), (3, 3));
+    assert_eq!(scaled.frames[0].lines, vec![vec![segment(0, "⣿⠀⢸")]; 3]);
+
+    // Not rounded to a third, the last row only gets one dot.
+    let scaled = art.scale(0.3);
+    assert_eq!((scaled.height(), scaled.width()), (2, 2));
+    assert_eq!(scaled.frames[0].lines[0], [segment(0, "⡇⡇")]);
+    assert_eq!(scaled.frames[0].lines[1], [segment(0, "⠁⠁")]);
+}
+
+#[test]
+fn normalizes() {
+    assert_eq!(art::normalize("⠀⣰  \r\n⡼\t\r\n\r\n\n"), "⠀⣰\n⡼");
+
+    // Tabs go to the next stop, counting display columns.
+    let art = Art::parse("日\t⠛\n\t${c2}⠛");
+    assert_eq!(art.frames[0].lines[0], [segment(0, "日      ⠛")]);
+    assert_eq!(art.width(), 9);
+}
+
+#[test]
+fn check() {
+    assert!(art::check("colors: white\n⠀⣰\n⡼⢰\n").is_empty());
+
+    assert_eq!(
+        art::check("colors: white pink\r\n⠀⣰ \r\n\t${c9}\u{1b}\nframe: soon\n⡼\u{200b}·"),
+        [
+            "CR line endings",
+            "line 1: unknown color pink",
+            "line 2: trailing whitespace",
+            "line 3: unknown marker ${c9}",
+            "line 3: tab",
+            "line 3: control character U+001B",
+            "line 4: invalid frame delay soon",
+            "line 5: zero-width character U+200B",
+            "line 5: U+00B7 is wider in some terminals",
+        ]
+    );
+}
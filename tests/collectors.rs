@@ -0,0 +1,194 @@
+#![cfg(all(target_os = "linux", feature = "host", feature = "specs", feature = "net"))]
+
+use boykisserfetch::{
+    helpers::file::SysRoot,
+    system::{distro, host, locale, net, specs, terminal, virt},
+};
+
+fn fixture(name: &str) -> SysRoot {
+    SysRoot::new(format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name))
+}
+
+#[test]
+fn distro_names() {
+    let cases = [
+        ("arch", "Arch Linux (rolling)"),
+        ("debian", "Debian GNU/Linux 12 (bookworm)"),
+        ("ubuntu", "Ubuntu 22.04.4 LTS"),
+        ("fedora", "Fedora Linux 40 (Workstation Edition)"),
+        ("alpine", "Alpine Linux v3.20"),
+        ("quoted", "Quoted OS 1.0 \"Escaped\" Edition"),
+        ("lsb", "Linux Mint 21.3"),
+        ("centos6", "CentOS release 6.10 (Final)"),
+    ];
+
+    for (name, expected) in cases {
+        assert_eq!(fixture(name).enter(distro::get_distro), expected, "fixture {}", name);
+    }
+}
+
+#[test]
+fn distro_accent_colors() {
+    let cases = [
+        ("arch", None),
+        ("debian", None),
+        ("fedora", None),
+        ("alpine", Some("blue")),
+        ("quoted", Some("bright_red")),
+    ];
+
+    for (name, expected) in cases {
+        let color = fixture(name).enter(distro::get_accent_color);
+        assert_eq!(color.as_deref(), expected, "fixture {}", name);
+    }
+}
+
+#[test]
+fn cpu_models() {
+    let cases = [
+        ("amd", "AMD Ryzen 3 3200G with Radeon Vega Graphics (2C/4T) @ 2.40/3.60 GHz"),
+        ("rpi4", "ARM Cortex-A72 (4C/4T)"),
+        ("intel-hybrid", "12th Gen Intel(R) Core(TM) i5-1235U (2P+2E/6T)"),
+    ];
+
+    for (name, expected) in cases {
+        assert_eq!(fixture(name).enter(specs::get_cpu), expected, "fixture {}", name);
+    }
+}
+
+#[test]
+fn cpu_temperature() {
+    assert_eq!(fixture("amd").enter(specs::get_cpu_temperature), "45.5°C");
+    assert_eq!(fixture("rpi4").enter(specs::get_cpu_temperature), "51.1°C");
+    assert_eq!(fixture("intel-hybrid").enter(specs::get_cpu_temperature), "Unknown");
+
+    // Inline on the CPU line, left out without a sensor.
+    assert_eq!(
+        fixture("amd").enter(specs::get_cpu_with_temperature),
+        "AMD Ryzen 3 3200G with Radeon Vega Graphics (2C/4T) @ 2.40/3.60 GHz, 45.5°C"
+    );
+    assert_eq!(
+        fixture("intel-hybrid").enter(specs::get_cpu_with_temperature),
+        "12th Gen Intel(R) Core(TM) i5-1235U (2P+2E/6T)"
+    );
+}
+
+#[test]
+fn cpu_usage() {
+    let before = [(100, 1000), (50, 500), (50, 500)];
+    let after = [(600, 2000), (550, 1000), (50, 1000)];
+
+    assert_eq!(specs::format_cpu_usage(&before, &after, false), "50%");
+    assert_eq!(specs::format_cpu_usage(&before, &after, true), "50% █▁");
+    assert_eq!(specs::format_cpu_usage(&before, &before, true), "0% ▁▁");
+}
+
+#[test]
+fn battery() {
+    // The mains adapter isn't a battery.
+    assert_eq!(fixture("laptop").enter(specs::get_battery), "85% (Discharging), 100% (Full)");
+    assert_eq!(fixture("amd").enter(specs::get_battery), "Unknown");
+}
+
+#[test]
+fn timezones() {
+    // A symlink into zoneinfo, and a copied zone file next to /etc/timezone.
+    assert_eq!(fixture("arch").enter(locale::get_system_timezone).as_deref(), Some("Europe/Berlin"));
+    assert_eq!(fixture("debian").enter(locale::get_system_timezone).as_deref(), Some("America/New_York"));
+    assert_eq!(fixture("alpine").enter(locale::get_system_timezone), None);
+}
+
+#[test]
+fn locales() {
+    let resolve = |vars: &[(&str, &str)]| locale::resolve_locale(|name| {
+        vars.iter().find(|(var, _)| *var == name).map(|(_, value)| value.to_string())
+    });
+
+    assert_eq!(resolve(&[]), "C");
+    assert_eq!(resolve(&[("LANG", "en_US.UTF-8")]), "en_US.UTF-8");
+    assert_eq!(resolve(&[("LANG", "en_US.UTF-8"), ("LC_ALL", "de_DE.UTF-8")]), "de_DE.UTF-8");
+    assert_eq!(
+        resolve(&[("LANG", "en_US.UTF-8"), ("LC_TIME", "en_GB.UTF-8"), ("LC_CTYPE", "en_US.UTF-8")]),
+        "en_US.UTF-8 (LC_TIME=en_GB.UTF-8)"
+    );
+    assert_eq!(resolve(&[("LANG", ""), ("LC_CTYPE", "C.UTF-8")]), "C (LC_CTYPE=C.UTF-8)");
+}
+
+#[test]
+fn host_model() {
+    assert_eq!(fixture("amd").enter(specs::get_host_model), "Unknown");
+    assert_eq!(fixture("rpi4").enter(specs::get_host_model), "Raspberry Pi 4 Model B Rev 1.4");
+    assert_eq!(
+        fixture("intel-hybrid").enter(specs::get_host_model),
+        "LENOVO 21AH00BXUS ThinkPad T14 Gen 3"
+    );
+}
+
+#[test]
+fn firmware() {
+    let amd = fixture("amd");
+
+    assert_eq!(amd.enter(specs::get_bios), "3211 (08/10/2021)");
+    assert_eq!(amd.enter(specs::get_motherboard), "ASUSTeK COMPUTER INC. PRIME B450M-A");
+    assert_eq!(fixture("rpi4").enter(specs::get_bios), "Unknown");
+}
+
+#[test]
+fn memory_and_load() {
+    let amd = fixture("amd");
+
+    assert_eq!(amd.enter(specs::get_ram_used), "4000M / 16316M");
+    // No MemAvailable before Linux 3.14.
+    assert_eq!(fixture("centos6").enter(specs::get_ram_used), "824M / 1922M");
+    assert_eq!(fixture("rpi4").enter(specs::get_ram_used), "Unknown");
+    assert_eq!(amd.enter(specs::get_load), "0.52, 0.58, 0.59");
+    assert_eq!(amd.enter(host::get_uptime_seconds), Some(93784));
+    assert_eq!(fixture("rpi4").enter(specs::get_load), "Unknown");
+}
+
+#[test]
+fn virtualization() {
+    assert_eq!(fixture("vm").enter(virt::get_virtualization), "QEMU");
+    assert_eq!(fixture("wsl").enter(virt::get_virtualization), "WSL");
+    assert_eq!(fixture("docker").enter(virt::get_virtualization), "Docker");
+    assert_eq!(fixture("podman").enter(virt::get_virtualization), "Podman");
+    assert_eq!(fixture("amd").enter(virt::get_virtualization), "Unknown");
+}
+
+#[test]
+fn ip_address() {
+    assert_eq!(fixture("network").enter(net::get_ipaddr), "192.168.1.42 (wlan0)");
+    assert_eq!(fixture("rpi4").enter(net::get_ipaddr), "Unknown");
+}
+
+#[test]
+fn network_rate() {
+    let before = fixture("network").enter(net::sample_net_bytes);
+    assert_eq!(before, Some(("wlan0".to_string(), 813091039, 20213412)));
+
+    let after = Some(("wlan0".to_string(), 813091039 + 3 * 1024 * 1024, 20213412 + 1024));
+    let elapsed = std::time::Duration::from_secs(2);
+    assert_eq!(net::format_net_rate(&before, &after, elapsed), "1.5 MiB/s down, 512.0 B/s up (wlan0)");
+
+    // The default route moved to another interface in between.
+    let moved = Some(("eth0".to_string(), 0, 0));
+    assert_eq!(net::format_net_rate(&before, &moved, elapsed), "Unknown");
+    assert_eq!(fixture("rpi4").enter(net::sample_net_bytes), None);
+}
+
+#[test]
+fn process_tree() {
+    let process = fixture("process");
+
+    assert_eq!(process.enter(host::get_shell), "zsh");
+    assert_eq!(process.enter(terminal::get_terminal), "kitty");
+    assert_eq!(process.enter(|| host::get_init_system(true)), "s6");
+}
+
+#[test]
+fn root_is_restored() {
+    let root = SysRoot::current();
+
+    fixture("arch").enter(|| assert_ne!(SysRoot::current(), root));
+    assert_eq!(SysRoot::current(), root);
+}
@@ -0,0 +1,169 @@
+#![cfg(all(feature = "host", feature = "specs", feature = "net"))]
+
+use boykisserfetch::{
+    helpers::command::{self, CommandRunner, RecordingRunner, ReplayRunner},
+    system::{custom::{self, CustomModule}, host, net, specs},
+};
+
+fn replay(name: &str) -> ReplayRunner {
+    ReplayRunner::new(format!(
+        "{}/tests/fixtures/{}/commands",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    ))
+}
+
+#[test]
+fn fixture_names() {
+    assert_eq!(command::fixture_name("lspci", &[]), "lspci");
+    assert_eq!(command::fixture_name("wmic", &["cpu", "get", "name"]), "wmic_cpu_get_name");
+    assert_eq!(
+        command::fixture_name("reg", &["query", "HKCU\\Console", "/v", "FaceName"]),
+        "reg_query_HKCU_Console_v_FaceName"
+    );
+}
+
+#[test]
+fn missing_commands() {
+    assert_eq!(replay("windows").enter(specs::get_lspci_gpus), Vec::<String>::new());
+    assert_eq!(replay("linux-tools").enter(specs::get_wmic_cpu), None);
+}
+
+#[test]
+fn windows_host() {
+    let windows = || replay("windows");
+
+    assert_eq!(windows().enter(host::get_reg_hostname).as_deref(), Some("DESKTOP-K1TTY"));
+    assert_eq!(windows().enter(host::get_reg_user).as_deref(), Some("Silly Cat"));
+    assert_eq!(windows().enter(host::get_reg_shell).as_deref(), Some("PowerShell"));
+    assert_eq!(
+        windows().enter(host::get_wmic_resolution).as_deref(),
+        Some("2560x1440, 1920x1080")
+    );
+    assert_eq!(windows().enter(host::get_wmic_uptime_seconds), Some(93784));
+}
+
+#[test]
+fn windows_specs() {
+    let windows = || replay("windows");
+
+    assert_eq!(
+        windows().enter(specs::get_wmic_cpu).as_deref(),
+        Some("AMD Ryzen 7 5800X 8-Core Processor")
+    );
+    assert_eq!(windows().enter(specs::get_wmic_ram_used).as_deref(), Some("8192MB / 16384MB"));
+    assert_eq!(
+        windows().enter(specs::get_wmic_product).as_deref(),
+        Some("Microsoft Windows 11 Pro")
+    );
+    assert_eq!(
+        windows().enter(specs::get_wmic_disk_usage).as_deref(),
+        Some("C: 376GB / 476GB, E: 131GB / 931GB")
+    );
+    assert_eq!(windows().enter(specs::get_wmic_arch).as_deref(), Some("64-bit"));
+    assert_eq!(
+        windows().enter(specs::get_wmic_gpus),
+        ["NVIDIA GeForce RTX 3070", "AMD Radeon(TM) Graphics"]
+    );
+    assert_eq!(windows().enter(net::get_ipconfig_addr).as_deref(), Some("192.168.1.64"));
+}
+
+#[test]
+fn macos() {
+    let macos = || replay("macos");
+
+    assert_eq!(
+        macos().enter(host::get_scutil_hostname).as_deref(),
+        Some("Boykisser's MacBook Pro")
+    );
+    assert_eq!(macos().enter(host::get_osascript_resolution).as_deref(), Some("1512x982"));
+    assert_eq!(macos().enter(host::get_sysctl_boot_time), Some(1700000000));
+    assert_eq!(macos().enter(specs::get_sysctl_cpu).as_deref(), Some("Apple M2 Pro"));
+    assert_eq!(
+        macos().enter(specs::get_vm_stat_ram_used).as_deref(),
+        Some("6.0 GiB / 16.0 GiB")
+    );
+    assert_eq!(macos().enter(specs::get_system_profiler_gpus), ["Apple M2 Pro"]);
+}
+
+#[test]
+fn lspci() {
+    assert_eq!(
+        replay("linux-tools").enter(specs::get_lspci_gpus),
+        ["Intel Corporation UHD Graphics 620", "NVIDIA Corporation GP108M [GeForce MX150]"]
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn display_servers() {
+    let tools = || replay("linux-tools");
+    let monitors = |monitors: &[&str]| Some(monitors.iter().map(|m| m.to_string()).collect());
+
+    assert_eq!(
+        tools().enter(host::get_xrandr_monitors),
+        monitors(&["1920x1080 @ 60Hz", "2560x1440 @ 144Hz"])
+    );
+    assert_eq!(
+        tools().enter(host::get_hyprctl_monitors),
+        monitors(&["2560x1440 @ 144Hz", "1920x1200 @ 60Hz"])
+    );
+    assert_eq!(tools().enter(host::get_swaymsg_monitors), monitors(&["2560x1440 @ 144Hz"]));
+    assert_eq!(tools().enter(host::get_wlr_randr_monitors), monitors(&["2560x1440 @ 144Hz"]));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn ifconfig_fallback() {
+    use boykisserfetch::helpers::file::SysRoot;
+
+    let root = SysRoot::new(format!(
+        "{}/tests/fixtures/network-ifconfig",
+        env!("CARGO_MANIFEST_DIR")
+    ));
+
+    let addr = root.enter(|| replay("linux-tools").enter(net::get_ipaddr));
+    assert_eq!(addr, "10.0.0.23 (wlan0)");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn android_getprop() {
+    use boykisserfetch::{helpers::file::SysRoot, system::distro};
+
+    // No os-release or *-release files at all, only getprop answers.
+    let root = SysRoot::new(format!("{}/tests/fixtures/linux-tools", env!("CARGO_MANIFEST_DIR")));
+
+    let distro = root.enter(|| replay("linux-tools").enter(distro::get_distro));
+    assert_eq!(distro, "Android 14");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn init_services() {
+    use boykisserfetch::helpers::file::SysRoot;
+
+    let root = SysRoot::new(format!("{}/tests/fixtures/systemd", env!("CARGO_MANIFEST_DIR")));
+    let init = |services| root.enter(|| replay("systemd").enter(|| host::get_init_system(services)));
+
+    // Counting units runs systemctl twice, so it's left out unless asked for.
+    assert_eq!(init(false), "systemd 255");
+    assert_eq!(init(true), "systemd 255 (3 running, 1 failed)");
+}
+
+#[cfg(unix)]
+#[test]
+fn custom_commands() {
+    let dir = std::env::temp_dir().join(format!("boykisserfetch-record-{}", std::process::id()));
+    let module = CustomModule::parse("Greeting:echo 'hi there'", false).unwrap();
+    let timeout = std::time::Duration::from_secs(5);
+
+    // Recorded like any other command, then replayed without a shell.
+    let recorded = RecordingRunner::new(&dir).enter(|| custom::collect(&module, timeout));
+    let replayed = ReplayRunner::new(&dir).enter(|| custom::collect(&module, timeout));
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert_eq!(recorded, "hi there");
+    assert_eq!(replayed, "hi there");
+    assert_eq!(replay("macos").enter(|| custom::collect(&module, timeout)), "Unknown");
+}
@@ -0,0 +1,62 @@
+use boykisserfetch::helpers::{
+    art::{display_width, Art},
+    boykissers::get_boykisser,
+    paths::get_boykissers,
+    print::boykisser_line,
+};
+
+// Every row of every frame as `print_boykisserline` draws it, minus colors.
+fn render(art: &Art) -> String {
+    let mut output = String::new();
+
+    for frame in 0..art.frames.len() {
+        if art.is_animated() {
+            output.push_str(&format!("frame {}\n", frame + 1));
+        }
+
+        for line in 0..art.height() as u16 {
+            let text = boykisser_line(line, frame, art)
+                .into_iter()
+                .map(|segment| segment.text)
+                .collect::<String>();
+
+            assert_eq!(display_width(&text), art.width(), "row {} isn't padded", line);
+
+            output.push_str(&text);
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+#[test]
+fn builtin_art() {
+    let dir = format!("{}/tests/fixtures/golden", env!("CARGO_MANIFEST_DIR"));
+
+    for name in get_boykissers() {
+        // Some art is only embedded on some platforms.
+        let Some(boykisser) = get_boykisser(name.to_string()) else { continue };
+        let output = render(&boykisser.art);
+        let path = format!("{}/{}.txt", dir, name);
+
+        // `UPDATE_GOLDEN=1 cargo test` rewrites the files after an intended change.
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::write(&path, &output).unwrap();
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("{} is missing, run with UPDATE_GOLDEN=1", path));
+
+        assert!(output == expected, "{} no longer matches {}", name, path);
+    }
+}
+
+#[test]
+fn verbatim() {
+    let art = Art::parse("\\(\"^\")/\n日本 ${c2}'\\u{41}'");
+
+    // Wide characters count twice when padding.
+    assert_eq!(render(&art), "\\(\"^\")/      \n日本 '\\u{41}'\n");
+}
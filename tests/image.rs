@@ -0,0 +1,57 @@
+#![cfg(feature = "image")]
+
+use boykisserfetch::helpers::image::{base64, Picture, Protocol};
+
+fn gradient(rows: u16) -> Picture {
+    let path = format!("{}/tests/fixtures/images/gradient.png", env!("CARGO_MANIFEST_DIR"));
+
+    Picture::load(&path, rows, Protocol::HalfBlocks).unwrap()
+}
+
+#[test]
+fn encodes_base64() {
+    assert_eq!(base64(b""), "");
+    assert_eq!(base64(b"f"), "Zg==");
+    assert_eq!(base64(b"fo"), "Zm8=");
+    assert_eq!(base64(b"foo"), "Zm9v");
+    assert_eq!(base64(b"boykisser"), "Ym95a2lzc2Vy");
+}
+
+#[test]
+fn protocol_names() {
+    assert_eq!(Protocol::from_name("blocks"), Some(Protocol::HalfBlocks));
+    assert_eq!(Protocol::from_name("sixel"), Some(Protocol::Sixel));
+    assert_eq!(Protocol::from_name("png"), None);
+}
+
+#[test]
+fn scales_to_rows() {
+    // 8x6 pixels drawn 3 rows high keeps the size, 6 rows high doubles it.
+    let picture = gradient(3);
+    assert_eq!((picture.cols, picture.rows), (8, 3));
+
+    let picture = gradient(6);
+    assert_eq!((picture.cols, picture.rows), (16, 6));
+}
+
+#[test]
+fn half_blocks() {
+    let picture = gradient(3);
+    let line = picture.half_block_line(0);
+
+    // The top left pixel is transparent, so only the lower half is drawn.
+    assert!(line.starts_with("\u{1b}[49m\u{1b}[38;2;0;51;128m▄"));
+    assert_eq!(line.matches('▀').count() + line.matches('▄').count(), 8);
+    assert!(line.ends_with("\u{1b}[0m"));
+
+    // Rows below the picture are padded to its width.
+    assert_eq!(picture.half_block_line(5).matches(' ').count(), 8);
+}
+
+#[test]
+fn rejects_other_files() {
+    let path = format!("{}/Cargo.toml", env!("CARGO_MANIFEST_DIR"));
+
+    assert!(Picture::load(&path, 3, Protocol::HalfBlocks).is_err());
+    assert!(Picture::load("missing.png", 3, Protocol::HalfBlocks).is_err());
+}
@@ -0,0 +1,94 @@
+#![cfg(all(feature = "host", feature = "specs", feature = "net"))]
+
+use boykisserfetch::{
+    modules::registry::Registry,
+    system::custom::{self, CustomModule},
+};
+#[cfg(target_os = "linux")]
+use {
+    boykisserfetch::modules::builtin::Sampler,
+    std::sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[test]
+fn volatile_modules() {
+    let mut registry = Registry::builtin();
+    registry.register(Box::new(CustomModule::parse("Weather:curl wttr.in", false).unwrap()));
+
+    let is_volatile = |id: &str| registry.get(id).map(|module| module.is_volatile());
+
+    // --watch only collects these again.
+    assert_eq!(is_volatile("uptime"), Some(true));
+    assert_eq!(is_volatile("memory"), Some(true));
+    assert_eq!(is_volatile("ip"), Some(true));
+    assert_eq!(is_volatile("weather"), Some(true));
+    assert_eq!(is_volatile("cpu"), Some(false));
+    assert_eq!(is_volatile("shell"), Some(false));
+}
+
+#[test]
+fn custom_ids() {
+    let taken = ["cpu", "cpu-2", "uptime"];
+    let unique = |value: &str| {
+        CustomModule::parse(value, false).unwrap().with_unique_id(|id| taken.contains(&id)).id
+    };
+
+    // Built-in ids stay with the built-in modules.
+    assert_eq!(unique("CPU:echo fast"), "cpu-3");
+    assert_eq!(unique("Uptime:uptime -p"), "uptime-2");
+    assert_eq!(unique("On Call:echo nobody"), "on-call");
+}
+
+#[test]
+fn layout_ids_are_taken() {
+    let registry = Registry::builtin();
+    let unique = |value: &str| {
+        CustomModule::parse(value, false).unwrap().with_unique_id(|id| registry.is_taken(id)).id
+    };
+
+    // "title", "separator" and "colors" would be drawn by the layout instead.
+    assert_eq!(unique("Colors:echo hi"), "colors-2");
+    assert_eq!(unique("Separator:echo hi"), "separator-2");
+    assert_eq!(unique("Title:echo hi"), "title-2");
+    assert_eq!(unique("Uptime:uptime -p"), "uptime-2");
+}
+
+#[cfg(unix)]
+#[test]
+fn command_near_timeout() {
+    // Exits well before the deadline, with its output arriving after the exit.
+    let module = CustomModule::parse("Late:sleep 0.5; (sleep 0.2; echo done) &", false).unwrap();
+    assert_eq!(custom::collect(&module, std::time::Duration::from_millis(3000)), "done");
+
+    let module = CustomModule::parse("Slow:sleep 2; echo done", false).unwrap();
+    assert_eq!(custom::collect(&module, std::time::Duration::from_millis(100)), "Timed out");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn sampler_reuses_samples() {
+    static SAMPLES: AtomicUsize = AtomicUsize::new(0);
+    let sample = || SAMPLES.fetch_add(1, Ordering::SeqCst);
+
+    let sampler = Sampler::default();
+    let wait = std::time::Duration::ZERO;
+
+    // Only the first measurement takes two samples, the next one starts from
+    // where the previous one ended.
+    let (before, after, _) = sampler.measure(wait, sample);
+    assert_eq!((before, after), (0, 1));
+
+    let (before, after, _) = sampler.measure(wait, sample);
+    assert_eq!((before, after), (1, 2));
+    assert_eq!(SAMPLES.load(Ordering::SeqCst), 3);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn rate_modules_are_volatile() {
+    let registry = Registry::builtin();
+
+    for id in ["cpu-usage", "battery", "network-rate"] {
+        assert!(registry.get(id).is_some_and(|module| module.is_volatile()), "{}", id);
+    }
+}
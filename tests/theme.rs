@@ -0,0 +1,72 @@
+use boykisserfetch::helpers::theme::{Theme, DELIMITER_WIDTH};
+
+#[test]
+fn parse() {
+    let theme = Theme::parse(
+        "# comment\n\nboykisser = yippie\nvalue = cyan\nseparator = \" │ \"\nblocks = ●"
+    ).unwrap();
+
+    assert_eq!(theme.boykisser.as_deref(), Some("yippie"));
+    assert_eq!(theme.value.as_deref(), Some("cyan"));
+    assert_eq!(theme.separator, " │ ");
+    assert_eq!(theme.blocks, "●");
+
+    // Everything else keeps the default look.
+    assert_eq!(theme.secondary, Theme::default().secondary);
+    assert_eq!(theme.delimiter_line(), "-".repeat(DELIMITER_WIDTH));
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(Theme::parse("primary = pink").unwrap_err(), "line 1: unknown color pink");
+    assert_eq!(Theme::parse("\nfont = comic").unwrap_err(), "line 2: unknown key font");
+    assert_eq!(Theme::parse("separator").unwrap_err(), "line 1: expected <key> = <value>");
+    assert!(Theme::load("not-a-theme").is_err());
+}
+
+#[test]
+fn labels() {
+    let mut theme = Theme::default();
+    assert_eq!(theme.format_label("Terminal Font"), "Terminal Font");
+
+    theme.set("label_case", "title").unwrap();
+    assert_eq!(theme.format_label("CPU temp"), "Cpu Temp");
+
+    theme.set("label_case", "upper").unwrap();
+    assert_eq!(theme.format_label("Init System"), "INIT SYSTEM");
+
+    theme.set("label_width", "20").unwrap();
+    assert_eq!(theme.label_width, Some(20));
+    theme.set("label_width", "auto").unwrap();
+    assert_eq!(theme.label_width, None);
+
+    assert_eq!(theme.set("indent", "-1").unwrap_err(), "invalid indent -1");
+    assert_eq!(theme.set("label_case", "shout").unwrap_err(), "unknown label case shout");
+}
+
+#[test]
+fn builtin_themes() {
+    for (name, _) in Theme::list_in(None) {
+        assert!(Theme::load_in(&name, None).is_ok(), "{} doesn't load", name);
+    }
+
+    assert_eq!(Theme::load_in("default", None).unwrap(), Theme::default());
+}
+
+#[test]
+fn user_themes() {
+    let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/config/boykisserfetch/themes");
+    let dir = Some(dir.as_path());
+
+    // User themes are listed too and replace built-in ones.
+    let themes = Theme::list_in(dir);
+    assert!(themes.contains(&(String::from("sunset"), true)));
+    assert!(themes.contains(&(String::from("mono"), true)));
+    assert!(themes.contains(&(String::from("pastel"), false)));
+
+    let sunset = Theme::load_in("sunset", dir).unwrap();
+    assert_eq!(sunset.primary.as_deref(), Some("yellow"));
+    assert_eq!(sunset.delimiter_line(), "~=".repeat(DELIMITER_WIDTH / 2));
+    assert_eq!(Theme::load_in("mono", dir).unwrap().boykisser, None);
+}